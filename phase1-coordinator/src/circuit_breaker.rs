@@ -0,0 +1,107 @@
+//! A closed/open/half-open circuit breaker guarding calls to dependencies
+//! that can fail in bursts (S3, the default verifier), so a degraded
+//! dependency doesn't get hammered with retries while it's recovering.
+
+use std::{
+    future::Future,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    },
+    time::{Duration, Instant},
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum State {
+    Closed,
+    Open,
+    HalfOpen,
+}
+
+/// Tracks consecutive failures of a single dependency and trips open once
+/// `failure_threshold` is reached, refusing calls until `reset_timeout` has
+/// elapsed, at which point one probe call is let through (half-open) to
+/// decide whether to close again or re-open.
+pub struct CircuitBreaker {
+    state: Mutex<(State, Instant)>,
+    consecutive_failures: AtomicUsize,
+    failure_threshold: usize,
+    reset_timeout: Duration,
+}
+
+/// The outcome of a call attempted through a tripped-open breaker, or a
+/// failure of the call itself.
+pub enum CircuitBreakerError<E> {
+    Open,
+    Inner(E),
+}
+
+impl CircuitBreaker {
+    pub fn new(failure_threshold: usize, reset_timeout: Duration) -> Self {
+        Self {
+            state: Mutex::new((State::Closed, Instant::now())),
+            consecutive_failures: AtomicUsize::new(0),
+            failure_threshold,
+            reset_timeout,
+        }
+    }
+
+    /// Returns `true` if a call should be let through right now, flipping
+    /// `Open` to `HalfOpen` once the reset timeout has elapsed. Only the
+    /// single call that performs that flip -- the probe -- is let through;
+    /// every other caller that finds the breaker already `HalfOpen` gets
+    /// `false` until `record_success`/`record_failure` resolves the probe,
+    /// so a burst of callers can't all pile onto a dependency that's still
+    /// recovering.
+    pub fn allow_call(&self) -> bool {
+        let mut guard = self.state.lock().expect("Circuit breaker lock poisoned");
+
+        match guard.0 {
+            State::Closed => true,
+            State::HalfOpen => false,
+            State::Open if guard.1.elapsed() >= self.reset_timeout => {
+                guard.0 = State::HalfOpen;
+                true
+            }
+            State::Open => false,
+        }
+    }
+
+    pub fn record_success(&self) {
+        self.consecutive_failures.store(0, Ordering::SeqCst);
+        self.state.lock().expect("Circuit breaker lock poisoned").0 = State::Closed;
+    }
+
+    pub fn record_failure(&self) {
+        let failures = self.consecutive_failures.fetch_add(1, Ordering::SeqCst) + 1;
+        let mut guard = self.state.lock().expect("Circuit breaker lock poisoned");
+
+        // A probe call made while half-open trips the breaker straight back
+        // open on any failure, without waiting for the full threshold.
+        if guard.0 == State::HalfOpen || failures >= self.failure_threshold {
+            *guard = (State::Open, Instant::now());
+        }
+    }
+
+    /// Runs `f` if the breaker currently allows calls, recording the outcome.
+    pub async fn call<F, Fut, T, E>(&self, f: F) -> Result<T, CircuitBreakerError<E>>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<T, E>>,
+    {
+        if !self.allow_call() {
+            return Err(CircuitBreakerError::Open);
+        }
+
+        match f().await {
+            Ok(value) => {
+                self.record_success();
+                Ok(value)
+            }
+            Err(e) => {
+                self.record_failure();
+                Err(CircuitBreakerError::Inner(e))
+            }
+        }
+    }
+}
@@ -0,0 +1,111 @@
+//! Cohort-gated queue admission. Per EXTERNAL DOC 5, a real ceremony doesn't
+//! accept just anyone who calls `add_to_queue` -- contributors are invited in
+//! batches ("cohorts", typically one per invite email blast or time window)
+//! and each invite carries a unique, single-use token. `CohortManager` tracks
+//! which cohort is currently open and which of its tokens have already been
+//! spent, so `Coordinator::add_to_queue` can gate admission on presenting an
+//! unused token for the open cohort.
+//!
+//! `storage.rs`/`objects.rs` are absent from this tree. This assumes a
+//! `Locator::CohortRegistry` / `Object::CohortRegistry(CohortManager)`
+//! singleton pair, mirroring the existing `Locator::CoordinatorState` --
+//! loaded once in `Coordinator::new` and persisted back by
+//! `Coordinator::save_cohort_manager` after every mutation.
+
+use crate::CoordinatorError;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// One cohort's admission tokens and whether it's currently open for
+/// `add_to_queue`. A token maps to whether it has already been spent.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Cohort {
+    open: bool,
+    tokens: HashMap<String, bool>,
+}
+
+impl Cohort {
+    fn new(tokens: impl IntoIterator<Item = String>) -> Self {
+        Self {
+            open: false,
+            tokens: tokens.into_iter().map(|token| (token, false)).collect(),
+        }
+    }
+}
+
+/// The set of all cohorts configured for this ceremony. See the module
+/// documentation for the overall design.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CohortManager {
+    cohorts: HashMap<String, Cohort>,
+}
+
+impl CohortManager {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers a new cohort with its set of valid tokens, closed by
+    /// default until an operator calls `open_cohort`.
+    pub fn register_cohort(&mut self, cohort_id: String, tokens: impl IntoIterator<Item = String>) -> Result<(), CoordinatorError> {
+        if self.cohorts.contains_key(&cohort_id) {
+            return Err(CoordinatorError::CohortAlreadyExists);
+        }
+        self.cohorts.insert(cohort_id, Cohort::new(tokens));
+        Ok(())
+    }
+
+    /// Opens `cohort_id` for admission, so a valid, unspent token for it
+    /// will be accepted by `consume`.
+    pub fn open_cohort(&mut self, cohort_id: &str) -> Result<(), CoordinatorError> {
+        self.cohort_mut(cohort_id)?.open = true;
+        Ok(())
+    }
+
+    /// Closes `cohort_id`, so its tokens are no longer accepted even if
+    /// unspent -- used to roll the ceremony from one cohort to the next.
+    pub fn close_cohort(&mut self, cohort_id: &str) -> Result<(), CoordinatorError> {
+        self.cohort_mut(cohort_id)?.open = false;
+        Ok(())
+    }
+
+    /// Consumes `token` for `cohort_id`, provided that cohort is currently
+    /// open and the token is valid and unspent.
+    ///
+    /// Marking the token spent happens here, unconditionally on success --
+    /// not contingent on whatever the caller does with the admission
+    /// afterwards -- so a token can never be used twice, even if the
+    /// participant it admitted is later dropped and re-queues.
+    pub fn consume(&mut self, cohort_id: &str, token: &str) -> Result<(), CoordinatorError> {
+        let cohort = self.cohort_mut(cohort_id)?;
+        if !cohort.open {
+            return Err(CoordinatorError::CohortNotOpen);
+        }
+
+        match cohort.tokens.get_mut(token) {
+            Some(spent) if !*spent => {
+                *spent = true;
+                Ok(())
+            }
+            Some(_) => Err(CoordinatorError::CohortTokenAlreadySpent),
+            None => Err(CoordinatorError::CohortTokenInvalid),
+        }
+    }
+
+    /// Marks `token` for `cohort_id` unspent again, undoing a `consume`
+    /// whose caller was unable to actually use the admission it granted
+    /// (e.g. `Coordinator::add_to_queue` failed after consuming it) -- so a
+    /// token that was presented in good faith but admitted no one isn't
+    /// burned for nothing.
+    pub fn restore(&mut self, cohort_id: &str, token: &str) -> Result<(), CoordinatorError> {
+        let cohort = self.cohort_mut(cohort_id)?;
+        if let Some(spent) = cohort.tokens.get_mut(token) {
+            *spent = false;
+        }
+        Ok(())
+    }
+
+    fn cohort_mut(&mut self, cohort_id: &str) -> Result<&mut Cohort, CoordinatorError> {
+        self.cohorts.get_mut(cohort_id).ok_or(CoordinatorError::CohortNotFound)
+    }
+}
@@ -0,0 +1,147 @@
+//! Loads the ceremony's mutable configuration from `config.toml` (and, if
+//! present, `/etc/<ceremony>/config.toml`), merged with environment variable
+//! and CLI flag overrides. This lets operators choose the contribution mode,
+//! curve/power parameters, round sizes, storage paths, and queue/ban
+//! thresholds without rebuilding the binary.
+
+use crate::environment::{Development, Environment, Parameters, Production};
+
+use figment::{
+    providers::{Env, Format, Serialized, Toml},
+    Figment,
+};
+use serde::{Deserialize, Serialize};
+
+/// Default location of the ceremony config file, relative to the working directory.
+pub const DEFAULT_CONFIG_PATH: &str = "config.toml";
+/// System-wide config location. It is merged before the local config file,
+/// so settings in the local file override it.
+pub const SYSTEM_CONFIG_PATH: &str = "/etc/phase1-coordinator/config.toml";
+
+/// Selects which `Environment` variant the coordinator boots with.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum EnvironmentKind {
+    Development,
+    Production,
+}
+
+impl Default for EnvironmentKind {
+    fn default() -> Self {
+        EnvironmentKind::Production
+    }
+}
+
+/// Selects which `Parameters` variant the coordinator runs with.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ParametersKind {
+    AleoInner,
+    /// Custom test parameters: `(power, batch_size, chunk_size)`.
+    TestCustom(u32, usize, usize),
+}
+
+impl Default for ParametersKind {
+    fn default() -> Self {
+        ParametersKind::AleoInner
+    }
+}
+
+/// Operator-tunable ceremony configuration.
+///
+/// Everything here is safe to merge from TOML, `COORDINATOR_`-prefixed
+/// environment variables, and CLI flags, in increasing priority order.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub struct CeremonyConfig {
+    pub environment: EnvironmentKind,
+    pub parameters: ParametersKind,
+    /// Caps how many participants may simultaneously hold a chunk lock and be
+    /// actively contributing.
+    pub max_active_contributors: usize,
+    /// The reliability score, out of 100, below which a participant is banned.
+    pub ban_threshold_score: u8,
+    /// How long a participant may go without activity before being dropped.
+    pub contributor_timeout_secs: u64,
+}
+
+/// The subset of `CeremonyConfig` that is safe to change on a running
+/// ceremony without restarting it. Cryptographic parameters (`environment`,
+/// `parameters`) are intentionally excluded: changing the curve or round
+/// sizes mid-ceremony would invalidate in-progress contributions.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RuntimeTunables {
+    pub max_active_contributors: usize,
+    pub ban_threshold_score: u8,
+    pub contributor_timeout_secs: u64,
+}
+
+impl CeremonyConfig {
+    /// Extracts the subset of this config that is safe to hot-reload at runtime.
+    pub fn runtime_tunables(&self) -> RuntimeTunables {
+        RuntimeTunables {
+            max_active_contributors: self.max_active_contributors,
+            ban_threshold_score: self.ban_threshold_score,
+            contributor_timeout_secs: self.contributor_timeout_secs,
+        }
+    }
+
+    /// Returns `true` if `other` only differs from `self` in fields that are
+    /// safe to apply to a running ceremony (i.e. it doesn't touch the
+    /// immutable cryptographic parameters).
+    pub fn is_safe_runtime_change(&self, other: &Self) -> bool {
+        self.environment == other.environment && self.parameters == other.parameters
+    }
+}
+
+/// Default cap on simultaneously active contributors, mirroring
+/// `coordinator::DEFAULT_MAX_ACTIVE_CONTRIBUTORS`.
+const DEFAULT_MAX_ACTIVE_CONTRIBUTORS: usize = 64;
+/// Default reliability-score ban threshold.
+const DEFAULT_BAN_THRESHOLD_SCORE: u8 = 10;
+/// Default contributor activity timeout, in seconds.
+const DEFAULT_CONTRIBUTOR_TIMEOUT_SECS: u64 = 3600;
+
+impl Default for CeremonyConfig {
+    fn default() -> Self {
+        Self {
+            environment: EnvironmentKind::default(),
+            parameters: ParametersKind::default(),
+            max_active_contributors: DEFAULT_MAX_ACTIVE_CONTRIBUTORS,
+            ban_threshold_score: DEFAULT_BAN_THRESHOLD_SCORE,
+            contributor_timeout_secs: DEFAULT_CONTRIBUTOR_TIMEOUT_SECS,
+        }
+    }
+}
+
+impl CeremonyConfig {
+    ///
+    /// Loads the ceremony configuration by merging, in order of increasing
+    /// priority: compiled-in defaults, the system config file, the local
+    /// config file, `COORDINATOR_`-prefixed environment variables, and any
+    /// CLI overrides.
+    ///
+    pub fn load(cli_overrides: Figment) -> anyhow::Result<Self> {
+        let figment = Figment::from(Serialized::defaults(Self::default()))
+            .merge(Toml::file(SYSTEM_CONFIG_PATH))
+            .merge(Toml::file(DEFAULT_CONFIG_PATH))
+            .merge(Env::prefixed("COORDINATOR_"))
+            .merge(cli_overrides);
+
+        Ok(figment.extract()?)
+    }
+
+    /// Resolves this configuration into the `Environment` the coordinator boots with.
+    pub fn into_environment(self) -> Environment {
+        let parameters = match self.parameters {
+            ParametersKind::AleoInner => Parameters::AleoInner,
+            ParametersKind::TestCustom(power, batch_size, chunk_size) => {
+                Parameters::TestCustom(power, batch_size, chunk_size)
+            }
+        };
+
+        match self.environment {
+            EnvironmentKind::Development => Development::from(parameters).into(),
+            EnvironmentKind::Production => Production::from(parameters).into(),
+        }
+    }
+}
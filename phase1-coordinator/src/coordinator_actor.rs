@@ -0,0 +1,98 @@
+//! A long-lived background task that exclusively drives coordinator
+//! mutations, fed by an `mpsc` command channel.
+//!
+//! `perform_coordinator_update` and `perform_verify_chunks` used to combine
+//! `RwLock::write_owned` with a non-abortable `spawn_blocking` directly on
+//! the request path: if the request future was dropped (client disconnect,
+//! `select!` losing a race, ...), the write guard and blocking task would
+//! outlive the cancelled request anyway, but nothing about that was
+//! *designed* to be safe — it just happened not to poison anything. Routing
+//! mutations through this actor instead makes that property explicit: HTTP
+//! handlers only send a command and await its `oneshot` reply. Awaiting a
+//! `oneshot::Receiver` is cancel-safe, so dropping that await drops only the
+//! receiver — the actor task keeps the command running to completion
+//! regardless, and no lock is ever stranded half-held by a cancelled future.
+
+use crate::{
+    rest_utils::{Coordinator, ResponseError, Result},
+    CoordinatorError,
+};
+
+use anyhow::anyhow;
+use tokio::{
+    sync::{mpsc, oneshot},
+    task,
+};
+use tracing::error;
+
+/// A single coordinator mutation, paired with the `oneshot::Sender` its
+/// result is delivered through.
+pub enum CoordinatorCommand {
+    Update {
+        reply: oneshot::Sender<Result<()>>,
+    },
+    VerifyPending {
+        reply: oneshot::Sender<Result<Vec<u8>>>,
+    },
+}
+
+/// A cheaply cloneable handle to the coordinator actor. Every method sends
+/// its command and awaits only the `oneshot` reply.
+#[derive(Clone)]
+pub struct CoordinatorActorHandle {
+    commands: mpsc::UnboundedSender<CoordinatorCommand>,
+}
+
+impl CoordinatorActorHandle {
+    /// Spawns the actor task owning `coordinator` and returns a handle to it.
+    pub fn spawn(coordinator: Coordinator) -> Self {
+        let (commands, mut receiver) = mpsc::unbounded_channel::<CoordinatorCommand>();
+
+        task::spawn(async move {
+            while let Some(command) = receiver.recv().await {
+                match command {
+                    CoordinatorCommand::Update { reply } => {
+                        let mut write_lock = coordinator.clone().write_owned().await;
+                        let result = task::spawn_blocking(move || write_lock.update())
+                            .await
+                            .map_err(|e| ResponseError::RuntimeError(e))
+                            .and_then(|r| r.map_err(ResponseError::CoordinatorError));
+                        let _ = reply.send(result);
+                    }
+                    CoordinatorCommand::VerifyPending { reply } => {
+                        let result = crate::rest_utils::verify_pending_contributions(coordinator.clone()).await;
+                        let _ = reply.send(result);
+                    }
+                }
+            }
+
+            error!("Coordinator actor's command channel closed, no more mutations can be driven");
+        });
+
+        Self { commands }
+    }
+
+    async fn send<T>(&self, make_command: impl FnOnce(oneshot::Sender<Result<T>>) -> CoordinatorCommand) -> Result<T> {
+        let (reply, receiver) = oneshot::channel();
+
+        self.commands.send(make_command(reply)).map_err(|_| {
+            ResponseError::CoordinatorError(CoordinatorError::Error(anyhow!("Coordinator actor has shut down")))
+        })?;
+
+        receiver.await.map_err(|_| {
+            ResponseError::CoordinatorError(CoordinatorError::Error(anyhow!(
+                "Coordinator actor dropped the reply before sending it"
+            )))
+        })?
+    }
+
+    /// Drives one coordinator update cycle.
+    pub async fn update(&self) -> Result<()> {
+        self.send(|reply| CoordinatorCommand::Update { reply }).await
+    }
+
+    /// Verifies all pending contributions, returning the resulting contributions summary.
+    pub async fn verify_pending(&self) -> Result<Vec<u8>> {
+        self.send(|reply| CoordinatorCommand::VerifyPending { reply }).await
+    }
+}
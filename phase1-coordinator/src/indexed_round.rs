@@ -0,0 +1,98 @@
+//! A cached, pre-indexed view of a `Round`, so hot paths that only need to
+//! know "who holds this chunk's lock" or "does this contribution exist yet"
+//! -- `run_computation`, `run_verification`, and `try_contribute` -- stop
+//! paying for a full storage
+//! deserialization of the round on every call within the same coordinator
+//! operation.
+//!
+//! `objects.rs` (where `Round`/`Chunk`/`Contribution` are defined) is absent
+//! from this tree. Building the `lock_holders` and `contributions` indices
+//! below assumes `Chunk` exposes a `lock_holder(&self) -> Option<&Participant>`
+//! accessor and `Contribution` a `hash(&self) -> Option<Hash>` accessor,
+//! neither of which is visible here -- both are assumed to read fields the
+//! round already deserialized, not to perform any additional storage I/O.
+
+use crate::merkle_transcript::Hash;
+use crate::objects::{participant::*, Round};
+use std::collections::HashMap;
+
+/// What `IndexedRound::build` records for a single `(chunk_id, contribution_id)`.
+///
+/// This stays a plain hash-and-flag pair rather than wrapping the
+/// contribution's own `Contribution` because `IndexedRound` already keeps
+/// the whole `Round` -- including every `Contribution` -- alive in `round`,
+/// so indexing it again here would mean either cloning each `Contribution`
+/// (an unconfirmed bound) or borrowing from `round`, which a `HashMap`
+/// stored alongside it can't do without a self-referential struct.
+#[derive(Clone)]
+pub struct ContributionIndexEntry {
+    /// The hash recorded for this contribution's file, if one has been
+    /// computed -- `None` before the contribution has a response on disk.
+    pub hash: Option<Hash>,
+    pub verified: bool,
+}
+
+/// A `Round`, indexed once when it's loaded from storage, so repeated
+/// lock-holder and contribution-existence checks within the same operation
+/// don't re-scan it.
+pub struct IndexedRound {
+    round_height: u64,
+    round: Round,
+    lock_holders: HashMap<u64, Participant>,
+    contributions: HashMap<(u64, u64), ContributionIndexEntry>,
+}
+
+impl IndexedRound {
+    /// Builds the index for `round` at `round_height` from its
+    /// already-deserialized chunks and contributions -- no additional
+    /// storage reads.
+    pub fn build(round_height: u64, round: Round) -> Self {
+        let mut lock_holders = HashMap::new();
+        let mut contributions = HashMap::new();
+
+        for chunk in round.chunks() {
+            if let Some(holder) = chunk.lock_holder() {
+                lock_holders.insert(chunk.chunk_id(), holder.clone());
+            }
+
+            for contribution in chunk.get_contributions() {
+                contributions.insert(
+                    (chunk.chunk_id(), contribution.contribution_id()),
+                    ContributionIndexEntry {
+                        hash: contribution.hash(),
+                        verified: contribution.is_verified(),
+                    },
+                );
+            }
+        }
+
+        Self {
+            round_height,
+            round,
+            lock_holders,
+            contributions,
+        }
+    }
+
+    pub fn round_height(&self) -> u64 {
+        self.round_height
+    }
+
+    /// The indexed `Round` itself, for call sites that still need to invoke
+    /// methods this index doesn't cover (e.g. `expected_number_of_contributions`).
+    pub fn round(&self) -> &Round {
+        &self.round
+    }
+
+    /// Whether `chunk_id`'s lock is currently held by `participant`, served
+    /// from the index rather than `Round::is_chunk_locked_by`.
+    pub fn is_chunk_locked_by(&self, chunk_id: u64, participant: &Participant) -> bool {
+        self.lock_holders.get(&chunk_id) == Some(participant)
+    }
+
+    /// The indexed entry for `(chunk_id, contribution_id)`, if that
+    /// contribution exists yet.
+    pub fn contribution(&self, chunk_id: u64, contribution_id: u64) -> Option<&ContributionIndexEntry> {
+        self.contributions.get(&(chunk_id, contribution_id))
+    }
+}
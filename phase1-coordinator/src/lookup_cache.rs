@@ -0,0 +1,110 @@
+//! A small LRU cache for participant/verifier status lookups used by the REST
+//! `FromRequest` guards. Each incoming request otherwise takes a coordinator
+//! read lock just to re-derive the same current-contributor/banned/dropped
+//! status that the last request for the same pubkey already computed; this
+//! caches that result for a short TTL and coalesces concurrent misses for the
+//! same key onto a single computation, so a burst of requests from one
+//! participant doesn't hammer the coordinator lock with duplicate lookups.
+
+use lru::LruCache;
+use std::{
+    collections::HashMap,
+    future::Future,
+    hash::Hash,
+    num::NonZeroUsize,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+use tokio::sync::OnceCell;
+
+/// Default number of recently-looked-up keys to retain.
+const DEFAULT_CAPACITY: usize = 1024;
+/// How long a cached lookup remains valid before it must be recomputed,
+/// bounding how stale a cached status can get.
+const DEFAULT_TTL: Duration = Duration::from_secs(5);
+
+struct CachedEntry<V> {
+    value: V,
+    cached_at: Instant,
+}
+
+/// A keyed LRU cache of `V`, with single-flight coalescing of concurrent
+/// misses for the same key.
+pub struct LookupCache<K, V> {
+    entries: Mutex<LruCache<K, CachedEntry<V>>>,
+    in_flight: Mutex<HashMap<K, Arc<OnceCell<V>>>>,
+    ttl: Duration,
+}
+
+impl<K, V> LookupCache<K, V>
+where
+    K: Eq + Hash + Clone,
+    V: Clone,
+{
+    pub fn new(capacity: usize, ttl: Duration) -> Self {
+        Self {
+            entries: Mutex::new(LruCache::new(
+                NonZeroUsize::new(capacity).unwrap_or_else(|| NonZeroUsize::new(DEFAULT_CAPACITY).unwrap()),
+            )),
+            in_flight: Mutex::new(HashMap::new()),
+            ttl,
+        }
+    }
+
+    pub fn with_defaults() -> Self {
+        Self::new(DEFAULT_CAPACITY, DEFAULT_TTL)
+    }
+
+    /// Returns the cached value for `key` if present and not yet stale,
+    /// otherwise coalesces with any concurrent lookup already in flight for
+    /// `key`, or runs `compute` itself if this is the first request for it.
+    pub async fn get_or_compute<F, Fut>(&self, key: K, compute: F) -> V
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = V>,
+    {
+        if let Some(value) = self.fresh_entry(&key) {
+            return value;
+        }
+
+        let cell = self
+            .in_flight
+            .lock()
+            .expect("Lookup cache in-flight lock poisoned")
+            .entry(key.clone())
+            .or_insert_with(|| Arc::new(OnceCell::new()))
+            .clone();
+
+        let value = cell.get_or_init(compute).await.clone();
+
+        self.entries.lock().expect("Lookup cache entries lock poisoned").put(
+            key.clone(),
+            CachedEntry {
+                value: value.clone(),
+                cached_at: Instant::now(),
+            },
+        );
+        // Drop the in-flight slot so the next miss (e.g. once this entry's
+        // TTL expires) starts a fresh coalescing group rather than reusing a
+        // `OnceCell` that's already been filled.
+        self.in_flight
+            .lock()
+            .expect("Lookup cache in-flight lock poisoned")
+            .remove(&key);
+
+        value
+    }
+
+    fn fresh_entry(&self, key: &K) -> Option<V> {
+        let mut entries = self.entries.lock().expect("Lookup cache entries lock poisoned");
+
+        match entries.get(key) {
+            Some(entry) if entry.cached_at.elapsed() <= self.ttl => Some(entry.value.clone()),
+            Some(_) => {
+                entries.pop(key);
+                None
+            }
+            None => None,
+        }
+    }
+}
@@ -1,44 +1,134 @@
-use phase1_coordinator::{
-    environment::{Development, Environment, Parameters, Production},
-    Coordinator,
-};
+use phase1_coordinator::{config::CeremonyConfig, coordinator::CoordinatorEvent, environment::Environment, Coordinator};
 
+use clap::Parser;
+use figment::{providers::Serialized, Figment};
 use std::time::Duration;
-use tokio::{task, time::sleep};
+use tokio::{task, time};
 use tracing::*;
+use warp::Filter;
+
+/// Fallback tick interval for housekeeping (ban/drop timeouts, periodic
+/// metrics) that isn't triggered by any particular coordinator event.
+const FALLBACK_TICK_INTERVAL: Duration = Duration::from_secs(10);
+
+/// How long a graceful shutdown waits for in-flight contributions to drain
+/// before forcing an exit.
+const SHUTDOWN_GRACE_PERIOD: Duration = Duration::from_secs(120);
+/// How often to poll for in-flight contributions to finish draining.
+const SHUTDOWN_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// How often to check the config file for hot-reloadable changes.
+const CONFIG_RELOAD_INTERVAL: Duration = Duration::from_secs(30);
+
+/// CLI overrides for the ceremony config, highest priority in the merge chain.
+#[derive(Parser, Debug, Default, serde::Serialize)]
+#[clap(name = "phase1-coordinator")]
+struct Opt {
+    /// Override the environment (development or production).
+    #[clap(long)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    environment: Option<String>,
+}
 
 #[inline]
-async fn coordinator(environment: &Environment) -> anyhow::Result<Coordinator> {
-    Ok(Coordinator::new(environment.clone())?)
+async fn coordinator(environment: &Environment, max_active_contributors: usize) -> anyhow::Result<Coordinator> {
+    Ok(Coordinator::new(environment.clone())?.with_max_active_contributors(max_active_contributors))
 }
 
+#[cfg(feature = "dhat-heap")]
+#[global_allocator]
+static ALLOC: dhat::Alloc = dhat::Alloc;
+
 #[tokio::main]
 pub async fn main() -> anyhow::Result<()> {
-    // Set the environment.
-    // let environment: Environment = Development::from(Parameters::TestCustom(8, 12, 256)).into();
-    let environment: Environment = Production::from(Parameters::AleoInner).into();
+    // Capture a heap profile for the lifetime of a long-running ceremony when
+    // built with `--features dhat-heap`; the profile is written out on drop.
+    #[cfg(feature = "dhat-heap")]
+    let _profiler = dhat::Profiler::new_heap();
+
+    // Load the ceremony configuration from `config.toml` / `/etc/phase1-coordinator/config.toml`,
+    // overridden by `COORDINATOR_`-prefixed environment variables and CLI flags.
+    let opt = Opt::parse();
+    let config = CeremonyConfig::load(Figment::from(Serialized::defaults(&opt)))?;
+    let max_active_contributors = config.max_active_contributors;
+    let environment: Environment = config.into_environment();
 
     // Instantiate the coordinator.
-    let coordinator = coordinator(&environment).await?;
+    let coordinator = coordinator(&environment, max_active_contributors).await?;
 
     // Initialize the coordinator.
     let operator = coordinator.clone();
+    let mut events = operator.take_event_receiver().expect("Event receiver already taken");
     let ceremony = task::spawn(async move {
         // Initialize the coordinator.
         operator.initialize().unwrap();
 
-        // Initialize the coordinator loop.
+        // Drive the update loop from events, falling back to a periodic tick
+        // for time-based housekeeping (ban/drop timeouts) when the ceremony
+        // is otherwise quiet, until a graceful shutdown is requested.
+        let mut tick = time::interval(FALLBACK_TICK_INTERVAL);
         loop {
-            // Run the update operation.
-            if let Err(error) = operator.update() {
+            let event = tokio::select! {
+                event = events.recv() => event.unwrap_or(CoordinatorEvent::Tick),
+                _ = tick.tick() => CoordinatorEvent::Tick,
+                _ = operator.wait_for_shutdown_request() => break,
+            };
+
+            if let Err(error) = operator.on_event(event) {
                 error!("{}", error);
             }
-
-            // Sleep for 10 seconds in between iterations.
-            sleep(Duration::from_secs(10)).await;
         }
     });
 
+    // Serve the Prometheus metrics registry at `/metrics`, and a readiness
+    // probe at `/readyz` for Docker healthchecks, systemd `Type=notify`-style
+    // supervisors, and test harnesses waiting on genuine readiness.
+    let metrics = coordinator.metrics();
+    let metrics_route = warp::path("metrics").map(move || metrics.encode());
+    let readiness = coordinator.clone();
+    let readyz_route = warp::path("readyz").map(move || match readiness.is_ready() {
+        true => warp::reply::with_status("ready", warp::http::StatusCode::OK),
+        false => warp::reply::with_status("not ready", warp::http::StatusCode::SERVICE_UNAVAILABLE),
+    });
+    task::spawn(warp::serve(metrics_route.or(readyz_route)).run(([0, 0, 0, 0], 9000)));
+
+    // Watch the config file for changes and, when a change leaves the
+    // cryptographic parameters alone, hand the new runtime tunables to
+    // `apply_runtime_tunables` without restarting the coordinator. Of those,
+    // only max active contributors takes effect; ban threshold and
+    // contributor timeout are logged but not applied.
+    {
+        let watched = coordinator.clone();
+        let mut last_config = CeremonyConfig::load(Figment::from(Serialized::defaults(&opt)))?;
+        task::spawn(async move {
+            let mut poll = time::interval(CONFIG_RELOAD_INTERVAL);
+            loop {
+                poll.tick().await;
+
+                let new_config = match CeremonyConfig::load(Figment::from(Serialized::defaults(&opt))) {
+                    Ok(config) => config,
+                    Err(error) => {
+                        warn!("Failed to reload ceremony config: {}", error);
+                        continue;
+                    }
+                };
+
+                if new_config == last_config {
+                    continue;
+                }
+
+                if !last_config.is_safe_runtime_change(&new_config) {
+                    error!("Ignoring config reload: it would change immutable cryptographic parameters");
+                    continue;
+                }
+
+                info!("Ceremony config changed, applying new runtime tunables");
+                watched.apply_runtime_tunables(new_config.runtime_tunables());
+                last_config = new_config;
+            }
+        });
+    }
+
     // Initialize the shutdown procedure.
     let handler = coordinator.clone();
     {
@@ -48,5 +138,22 @@ pub async fn main() -> anyhow::Result<()> {
 
     ceremony.await.expect("The ceremony handle has panicked");
 
-    Ok(())
+    // A graceful shutdown was requested: stop accepting new locks (already
+    // done by the signal handler), wait up to the grace period for in-flight
+    // contributions to finish or release their locks, then persist state.
+    let drain_start = time::Instant::now();
+    let clean_shutdown = loop {
+        if coordinator.in_flight_contributions() == 0 {
+            break true;
+        }
+        if drain_start.elapsed() >= SHUTDOWN_GRACE_PERIOD {
+            warn!("Shutdown grace period elapsed with contributions still in flight, forcing exit");
+            break false;
+        }
+        time::sleep(SHUTDOWN_POLL_INTERVAL).await;
+    };
+
+    coordinator.persist_on_shutdown()?;
+
+    std::process::exit(if clean_shutdown { 0 } else { 1 });
 }
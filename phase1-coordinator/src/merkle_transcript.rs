@@ -0,0 +1,169 @@
+//! An append-only Merkle accumulator, used by `coordinator.rs` to build a
+//! per-round transcript over the hashes of accepted contribution and
+//! verification files, so a participant can be handed a cryptographic
+//! inclusion proof that their submission made it into the canonical
+//! transcript.
+//!
+//! This is a Merkle Mountain Range: appending a leaf combines it with its
+//! left sibling and carries the combined node upward for as long as a
+//! sibling is available at the next level, and otherwise the node becomes
+//! an (unpaired) subtree root -- a "peak" -- at its level. The overall root
+//! is the fold of the current peaks, highest level first, with the running
+//! value always the *right* operand of `combine`; `proof`/`verify` agree
+//! with that same rule so a recomputed root matches [`MerkleTranscript::root`]
+//! exactly, regardless of how many peaks currently exist.
+
+use serde::{Deserialize, Serialize};
+use setup_utils::calculate_hash;
+
+/// A leaf or internal node hash. Matches `calculate_hash`'s return type, the
+/// same representation `VerificationVerdict::Valid`'s `hash` field already uses.
+pub type Hash = Vec<u8>;
+
+/// One step of an inclusion proof: the sibling hash to combine with, and
+/// whether the node already computed so far (`current`) is the *left*
+/// (`true`) or *right* (`false`) operand of that combination.
+pub type ProofStep = (Hash, bool);
+
+/// An append-only Merkle Mountain Range over opaque leaf hashes.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Default)]
+pub struct MerkleTranscript {
+    /// `levels[0]` is every leaf appended so far, in insertion order.
+    /// `levels[i]` for `i > 0` holds the level-`i` nodes built by pairing up
+    /// `levels[i - 1]`, growing lazily as enough nodes accumulate beneath it
+    /// to form a new pair.
+    levels: Vec<Vec<Hash>>,
+}
+
+impl MerkleTranscript {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The number of leaves appended so far.
+    pub fn len(&self) -> usize {
+        self.levels.first().map_or(0, Vec::len)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn combine(left: &Hash, right: &Hash) -> Hash {
+        let mut bytes = Vec::with_capacity(left.len() + right.len());
+        bytes.extend_from_slice(left);
+        bytes.extend_from_slice(right);
+        calculate_hash(&bytes)
+    }
+
+    /// Appends `leaf`, carrying completed pairs upward through `levels`,
+    /// and returns the index it was appended at.
+    pub fn append(&mut self, leaf: Hash) -> usize {
+        let index = self.len();
+
+        let mut level = 0;
+        let mut node = leaf;
+        loop {
+            if self.levels.len() == level {
+                self.levels.push(Vec::new());
+            }
+            self.levels[level].push(node.clone());
+
+            let nodes = &self.levels[level];
+            if nodes.len() % 2 != 0 {
+                // No sibling yet: `node` is this level's current peak.
+                break;
+            }
+
+            let right = nodes[nodes.len() - 1].clone();
+            let left = nodes[nodes.len() - 2].clone();
+            node = Self::combine(&left, &right);
+            level += 1;
+        }
+
+        index
+    }
+
+    /// The subtree roots ("peaks") that have not yet been paired with a
+    /// sibling, as `(level, hash)` pairs ordered from the lowest level to
+    /// the highest.
+    fn peaks(&self) -> Vec<(usize, Hash)> {
+        self.levels
+            .iter()
+            .enumerate()
+            .filter(|(_, nodes)| nodes.len() % 2 == 1)
+            .map(|(level, nodes)| (level, nodes.last().expect("odd length implies at least one node").clone()))
+            .collect()
+    }
+
+    /// Folds `peaks` (given lowest level first) into a single hash, highest
+    /// level first, with the running value as the right operand throughout.
+    fn fold_peaks(peaks: &[Hash]) -> Option<Hash> {
+        let mut highest_first = peaks.iter().rev();
+        let mut accumulator = highest_first.next()?.clone();
+        for peak in highest_first {
+            accumulator = Self::combine(peak, &accumulator);
+        }
+        Some(accumulator)
+    }
+
+    /// The current transcript root, or `None` if nothing has been appended yet.
+    pub fn root(&self) -> Option<Hash> {
+        let peaks: Vec<Hash> = self.peaks().into_iter().map(|(_, hash)| hash).collect();
+        Self::fold_peaks(&peaks)
+    }
+
+    /// Returns the inclusion proof for the leaf at `index`, or `None` if no
+    /// such leaf has been appended. Re-deriving the root from the leaf and
+    /// this proof is [`MerkleTranscript::verify`].
+    pub fn proof(&self, mut index: usize) -> Option<Vec<ProofStep>> {
+        if index >= self.len() {
+            return None;
+        }
+
+        let mut steps = Vec::new();
+        let mut level = 0;
+        while level < self.levels.len() {
+            let nodes = &self.levels[level];
+            let is_left = index % 2 == 0;
+            let sibling_index = if is_left { index + 1 } else { index - 1 };
+            if sibling_index >= nodes.len() {
+                // No sibling yet: this node is the current peak at `level`.
+                break;
+            }
+            steps.push((nodes[sibling_index].clone(), is_left));
+            index /= 2;
+            level += 1;
+        }
+
+        // `level` now names the peak this leaf currently resolves to. Fold
+        // in the peaks above it, then the peaks below it, in exactly the
+        // order `root` folds every peak, so the two agree on the result.
+        let peaks = self.peaks();
+
+        let higher: Vec<Hash> = peaks.iter().filter(|(lvl, _)| *lvl > level).map(|(_, hash)| hash.clone()).collect();
+        if let Some(folded_higher) = Self::fold_peaks(&higher) {
+            steps.push((folded_higher, true));
+        }
+        for (lvl, peak) in peaks.iter().rev() {
+            if *lvl < level {
+                steps.push((peak.clone(), false));
+            }
+        }
+
+        Some(steps)
+    }
+
+    /// Recomputes the root implied by `leaf` and its `proof`, for comparison
+    /// against a previously recorded [`MerkleTranscript::root`].
+    pub fn verify(leaf: &Hash, proof: &[ProofStep]) -> Hash {
+        let mut current = leaf.clone();
+        for (sibling, current_is_left) in proof {
+            current = match current_is_left {
+                true => Self::combine(&current, sibling),
+                false => Self::combine(sibling, &current),
+            };
+        }
+        current
+    }
+}
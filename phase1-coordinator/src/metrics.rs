@@ -0,0 +1,122 @@
+//! A Prometheus metrics registry for observing a running ceremony.
+//!
+//! The `Coordinator` holds one `CeremonyMetrics` and updates it at the points
+//! where locks, contributions, and round transitions occur, so operators can
+//! dashboard a long-running trusted setup and alert on stalls instead of
+//! relying solely on `tracing` log lines.
+
+use prometheus::{Encoder, Gauge, IntCounter, IntGauge, Registry, TextEncoder};
+use std::sync::Arc;
+
+/// Ceremony-wide Prometheus metrics, cheaply cloneable and safe to share
+/// across the coordinator and its HTTP metrics endpoint.
+#[derive(Clone)]
+pub struct CeremonyMetrics {
+    registry: Registry,
+    pub queue_length: IntGauge,
+    pub active_contributors: IntGauge,
+    pub active_verifiers: IntGauge,
+    pub active_contributor_permits: IntGauge,
+    pub current_round_height: IntGauge,
+    pub chunks_completed: IntGauge,
+    pub chunks_pending: IntGauge,
+    pub contributions_verified_total: IntCounter,
+    pub participants_banned_total: IntCounter,
+    pub participants_dropped_total: IntCounter,
+    pub update_cycle_seconds: Gauge,
+    /// How many tasks have been automatically dropped for running past
+    /// their compute-weight budget -- see `Coordinator::advance_compute_budgets`.
+    pub tasks_dropped_for_compute_budget_total: IntCounter,
+    /// The most recently completed task's actual elapsed time divided by
+    /// its expected compute-weight budget, so operators can tune
+    /// `Environment::expected_task_weight`/`compute_weight_budget_multiplier`.
+    pub task_weight_actual_vs_expected_ratio: Gauge,
+}
+
+impl CeremonyMetrics {
+    /// Creates a new metrics registry with all ceremony gauges/counters registered.
+    pub fn new() -> Arc<Self> {
+        let registry = Registry::new();
+
+        let queue_length = IntGauge::new("ceremony_queue_length", "Number of participants in the queue").unwrap();
+        let active_contributors =
+            IntGauge::new("ceremony_active_contributors", "Contributors in the current round").unwrap();
+        let active_verifiers = IntGauge::new("ceremony_active_verifiers", "Verifiers in the current round").unwrap();
+        let active_contributor_permits = IntGauge::new(
+            "ceremony_active_contributor_permits",
+            "Contributor permits currently checked out of the bounded pool",
+        )
+        .unwrap();
+        let current_round_height = IntGauge::new("ceremony_round_height", "Current round height").unwrap();
+        let chunks_completed = IntGauge::new("ceremony_chunks_completed", "Chunks completed this round").unwrap();
+        let chunks_pending = IntGauge::new("ceremony_chunks_pending", "Chunks still pending this round").unwrap();
+        let contributions_verified_total = IntCounter::new(
+            "ceremony_contributions_verified_total",
+            "Total contributions verified",
+        )
+        .unwrap();
+        let participants_banned_total =
+            IntCounter::new("ceremony_participants_banned_total", "Total participants banned").unwrap();
+        let participants_dropped_total =
+            IntCounter::new("ceremony_participants_dropped_total", "Total participants dropped").unwrap();
+        let update_cycle_seconds = Gauge::new(
+            "ceremony_update_cycle_seconds",
+            "Duration of the most recent update() cycle, in seconds",
+        )
+        .unwrap();
+        let tasks_dropped_for_compute_budget_total = IntCounter::new(
+            "ceremony_tasks_dropped_for_compute_budget_total",
+            "Total tasks automatically dropped for exceeding their compute-weight budget",
+        )
+        .unwrap();
+        let task_weight_actual_vs_expected_ratio = Gauge::new(
+            "ceremony_task_weight_actual_vs_expected_ratio",
+            "Most recently completed task's actual elapsed time divided by its expected compute-weight budget",
+        )
+        .unwrap();
+
+        for metric in [
+            Box::new(queue_length.clone()) as Box<dyn prometheus::core::Collector>,
+            Box::new(active_contributors.clone()),
+            Box::new(active_verifiers.clone()),
+            Box::new(active_contributor_permits.clone()),
+            Box::new(current_round_height.clone()),
+            Box::new(chunks_completed.clone()),
+            Box::new(chunks_pending.clone()),
+            Box::new(contributions_verified_total.clone()),
+            Box::new(participants_banned_total.clone()),
+            Box::new(participants_dropped_total.clone()),
+            Box::new(update_cycle_seconds.clone()),
+            Box::new(tasks_dropped_for_compute_budget_total.clone()),
+            Box::new(task_weight_actual_vs_expected_ratio.clone()),
+        ] {
+            registry.register(metric).expect("Metric names must not collide");
+        }
+
+        Arc::new(Self {
+            registry,
+            queue_length,
+            active_contributors,
+            active_verifiers,
+            active_contributor_permits,
+            current_round_height,
+            chunks_completed,
+            chunks_pending,
+            contributions_verified_total,
+            participants_banned_total,
+            participants_dropped_total,
+            update_cycle_seconds,
+            tasks_dropped_for_compute_budget_total,
+            task_weight_actual_vs_expected_ratio,
+        })
+    }
+
+    /// Renders all registered metrics in Prometheus text exposition format.
+    pub fn encode(&self) -> String {
+        let mut buffer = Vec::new();
+        TextEncoder::new()
+            .encode(&self.registry.gather(), &mut buffer)
+            .expect("Metric families must be encodable");
+        String::from_utf8(buffer).expect("Prometheus text output is always valid UTF-8")
+    }
+}
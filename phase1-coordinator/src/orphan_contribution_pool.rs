@@ -0,0 +1,178 @@
+//! A pool of contributions accepted by `add_contribution` whose immediate
+//! predecessor in the chunk's contribution chain has not yet been verified,
+//! mirroring a block-sync client's orphan-block pool: a response generated
+//! against a challenge that has since been superseded -- the round advanced
+//! between when a contributor fetched chunk N's challenge and when they
+//! uploaded their response -- is held here rather than rejected outright,
+//! and is written into the round automatically once its predecessor
+//! verifies. See `Coordinator::promote_pending_contributions`, called from
+//! `try_verify` right after a contribution verifies successfully.
+//!
+//! Bucketed by `(round_height, chunk_id, predecessor_contribution_id)`, so
+//! a single lookup after a predecessor verifies finds every contribution
+//! waiting on it. Each bucket is a `Vec<PendingContribution>` rather than a
+//! `HashMap<Participant, _>`, since `Participant` isn't `Hash` in this tree
+//! -- the same reasoning `verification_queue::ContributionKey` follows.
+//!
+//! Promotion only ever advances one link of the chain per call: writing a
+//! pending contribution into the round makes it the chunk's new current
+//! (unverified) contribution, which still requires a verifier's own
+//! `try_verify` call before *its* children can promote in turn. A single
+//! predecessor verifying can't cascade multiple links at once, since this
+//! repo's verification is a verifier's own decision, not something the
+//! pool can perform on a pending entry's behalf.
+
+use crate::Participant;
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+/// `(round_height, chunk_id, predecessor_contribution_id)` -- the bucket a
+/// [`PendingContribution`] waits in until that predecessor verifies.
+pub type OrphanKey = (u64, u64, u64);
+
+/// A contribution `add_contribution` accepted but could not yet write into
+/// the round, because its predecessor hasn't verified.
+#[derive(Debug, Clone)]
+pub struct PendingContribution {
+    pub round_height: u64,
+    pub chunk_id: u64,
+    pub contribution_id: u64,
+    pub participant: Participant,
+    /// Where the already-uploaded response file lives. Promotion re-derives
+    /// and re-validates this itself via `write_contribution`, but it's kept
+    /// here too for logging and dashboard use.
+    pub response_locator_path: String,
+    /// When this contribution was pooled, for TTL eviction.
+    pub queued_at: DateTime<Utc>,
+}
+
+impl PendingContribution {
+    fn predecessor_key(&self) -> OrphanKey {
+        (self.round_height, self.chunk_id, self.contribution_id.saturating_sub(1))
+    }
+}
+
+/// Returned by [`OrphanContributionPool::insert`] when the pool is already
+/// at capacity and the incoming contribution isn't a resubmission of one
+/// already held.
+#[derive(Debug)]
+pub struct PoolFull;
+
+struct Inner {
+    buckets: HashMap<OrphanKey, Vec<PendingContribution>>,
+    len: usize,
+}
+
+/// A bounded, TTL-evicting pool of [`PendingContribution`]s. See the module
+/// documentation for the overall design.
+pub struct OrphanContributionPool {
+    inner: Mutex<Inner>,
+    max_size: usize,
+    ttl: chrono::Duration,
+}
+
+impl OrphanContributionPool {
+    /// Creates an empty pool that holds at most `max_size` contributions
+    /// across all buckets, evicting any entry older than `ttl`.
+    pub fn new(max_size: usize, ttl: chrono::Duration) -> Self {
+        Self {
+            inner: Mutex::new(Inner {
+                buckets: HashMap::new(),
+                len: 0,
+            }),
+            max_size,
+            ttl,
+        }
+    }
+
+    /// Holds `pending` until its predecessor verifies. A second submission
+    /// from the same participant for the same contribution replaces its
+    /// earlier entry in place rather than occupying a second slot --
+    /// otherwise a contributor retrying after a dropped connection would
+    /// slowly exhaust the pool's capacity on its own.
+    pub fn insert(&self, pending: PendingContribution) -> Result<(), PoolFull> {
+        let key = pending.predecessor_key();
+        let mut inner = self.inner.lock().unwrap();
+
+        let bucket = inner.buckets.entry(key).or_insert_with(Vec::new);
+        if let Some(existing) = bucket.iter_mut().find(|p| p.participant == pending.participant) {
+            *existing = pending;
+            return Ok(());
+        }
+
+        if inner.len >= self.max_size {
+            return Err(PoolFull);
+        }
+
+        bucket.push(pending);
+        inner.len += 1;
+        Ok(())
+    }
+
+    /// Removes and returns every contribution waiting on
+    /// `(round_height, chunk_id, verified_contribution_id)` having just
+    /// verified -- the children ready to promote.
+    pub fn take_children(&self, round_height: u64, chunk_id: u64, verified_contribution_id: u64) -> Vec<PendingContribution> {
+        let mut inner = self.inner.lock().unwrap();
+        let removed = inner
+            .buckets
+            .remove(&(round_height, chunk_id, verified_contribution_id))
+            .unwrap_or_default();
+        inner.len -= removed.len();
+        removed
+    }
+
+    /// Re-pools `pending` after a promotion attempt found the chunk still
+    /// isn't ready for it (e.g. it was more than one contribution ahead),
+    /// without re-checking `max_size` -- it already occupied a slot before
+    /// `take_children` removed it.
+    pub fn reinsert_unchecked(&self, pending: PendingContribution) {
+        let key = pending.predecessor_key();
+        let mut inner = self.inner.lock().unwrap();
+        inner.buckets.entry(key).or_insert_with(Vec::new).push(pending);
+        inner.len += 1;
+    }
+
+    /// Drops every entry older than this pool's `ttl`, returning them so the
+    /// caller can log what was discarded -- a contributor whose predecessor
+    /// never verifies (e.g. it was banned) would otherwise hold a slot
+    /// indefinitely.
+    pub fn evict_expired(&self, now: DateTime<Utc>) -> Vec<PendingContribution> {
+        let mut inner = self.inner.lock().unwrap();
+        let ttl = self.ttl;
+        let mut expired = Vec::new();
+
+        inner.buckets.retain(|_, bucket| {
+            let (keep, gone): (Vec<_>, Vec<_>) = bucket.drain(..).partition(|p| now - p.queued_at < ttl);
+            *bucket = keep;
+            expired.extend(gone);
+            !bucket.is_empty()
+        });
+
+        inner.len -= expired.len();
+        expired
+    }
+
+    /// The `(chunk_id, contribution_id)` of every contribution currently
+    /// held in the pool, for a coordinator dashboard.
+    pub fn orphaned(&self) -> Vec<(u64, u64)> {
+        let inner = self.inner.lock().unwrap();
+        inner
+            .buckets
+            .values()
+            .flatten()
+            .map(|p| (p.chunk_id, p.contribution_id))
+            .collect()
+    }
+
+    /// The total number of contributions currently pooled, across all buckets.
+    pub fn len(&self) -> usize {
+        self.inner.lock().unwrap().len
+    }
+
+    /// Whether the pool currently holds no contributions.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
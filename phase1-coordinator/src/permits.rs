@@ -0,0 +1,131 @@
+//! A permit pool bounding how many participants may simultaneously hold a
+//! chunk lock and be actively contributing, protecting storage and bandwidth
+//! on a public ceremony.
+
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+use tokio::sync::{OwnedSemaphorePermit, Semaphore, TryAcquireError};
+
+/// Bundles an acquired permit with the chunk it was acquired for, so the
+/// permit cannot be leaked on an early return: dropping this guard (on
+/// completion, timeout, or participant drop) releases the permit back to
+/// the pool -- unless `resize` shrank the pool while this permit was
+/// checked out and is still waiting for a permit to forget, in which case
+/// this one is forgotten instead. See `ContributorPermitPool::resize`.
+pub struct ContributorPermit {
+    chunk_id: u64,
+    permit: Option<OwnedSemaphorePermit>,
+    pending_forget: Arc<AtomicUsize>,
+}
+
+impl ContributorPermit {
+    pub fn chunk_id(&self) -> u64 {
+        self.chunk_id
+    }
+}
+
+impl Drop for ContributorPermit {
+    fn drop(&mut self) {
+        let permit = match self.permit.take() {
+            Some(permit) => permit,
+            None => return,
+        };
+
+        // Claim one pending forget, if `resize` left any queued, instead of
+        // letting this permit return to the pool -- otherwise a shrink
+        // performed while every permit was checked out would be silently
+        // undone as each one finished.
+        let mut pending = self.pending_forget.load(Ordering::SeqCst);
+        loop {
+            if pending == 0 {
+                drop(permit);
+                return;
+            }
+            match self
+                .pending_forget
+                .compare_exchange_weak(pending, pending - 1, Ordering::SeqCst, Ordering::SeqCst)
+            {
+                Ok(_) => {
+                    permit.forget();
+                    return;
+                }
+                Err(actual) => pending = actual,
+            }
+        }
+    }
+}
+
+/// A `tokio::sync::Semaphore`-based pool of permits for concurrently active
+/// contributors, configured with `max_active_contributors`.
+#[derive(Clone)]
+pub struct ContributorPermitPool {
+    semaphore: Arc<Semaphore>,
+    max_active_contributors: Arc<AtomicUsize>,
+    /// Permits a `resize` shrink still owes forgetting, because they were
+    /// checked out (and so unavailable to `forget_permits`) at the time of
+    /// the shrink. Each `ContributorPermit::drop` claims one of these
+    /// instead of returning its permit to the pool, until the count is
+    /// drained back to zero.
+    pending_forget: Arc<AtomicUsize>,
+}
+
+impl ContributorPermitPool {
+    pub fn new(max_active_contributors: usize) -> Self {
+        Self {
+            semaphore: Arc::new(Semaphore::new(max_active_contributors)),
+            max_active_contributors: Arc::new(AtomicUsize::new(max_active_contributors)),
+            pending_forget: Arc::new(AtomicUsize::new(0)),
+        }
+    }
+
+    /// Attempts to acquire a permit for the given chunk without blocking.
+    /// Returns `None` if the pool is currently exhausted.
+    pub fn try_acquire(&self, chunk_id: u64) -> Option<ContributorPermit> {
+        match self.semaphore.clone().try_acquire_owned() {
+            Ok(permit) => Some(ContributorPermit {
+                chunk_id,
+                permit: Some(permit),
+                pending_forget: self.pending_forget.clone(),
+            }),
+            Err(TryAcquireError::NoPermits) | Err(TryAcquireError::Closed) => None,
+        }
+    }
+
+    /// Returns the number of permits currently checked out, for metrics.
+    pub fn in_use(&self) -> usize {
+        self.max_active_contributors
+            .load(Ordering::SeqCst)
+            .saturating_sub(self.semaphore.available_permits())
+    }
+
+    /// Resizes the pool to a new cap, for hot-reloading `max_active_contributors`
+    /// on a running ceremony. Permits already checked out are unaffected; the
+    /// new cap takes full effect once they're released.
+    ///
+    /// `Semaphore::forget_permits` only forgets permits that are currently
+    /// available -- it can't reach into a checked-out `OwnedSemaphorePermit`
+    /// to forget it early. Near/at capacity (precisely when an operator
+    /// would reach for this), most or all of a shrink's `reduction` has
+    /// nothing available to forget, and without the queue below, every
+    /// outstanding permit's ordinary `Drop` would `add_permits(1)` and
+    /// silently restore capacity toward the old, higher max. Queuing the
+    /// shortfall in `pending_forget` makes each outstanding permit forget
+    /// itself on release instead, until the shrink is fully honored.
+    pub fn resize(&self, new_max_active_contributors: usize) {
+        let current = self.max_active_contributors.swap(new_max_active_contributors, Ordering::SeqCst);
+
+        match new_max_active_contributors.checked_sub(current) {
+            Some(additional) if additional > 0 => self.semaphore.add_permits(additional),
+            _ => {
+                let reduction = current.saturating_sub(new_max_active_contributors);
+                let forgotten = self.semaphore.forget_permits(reduction);
+                let shortfall = reduction.saturating_sub(forgotten);
+                if shortfall > 0 {
+                    self.pending_forget.fetch_add(shortfall, Ordering::SeqCst);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,169 @@
+//! A [`Storage`] implementation that keeps small, frequently-read metadata
+//! locators (`RoundState`, `RoundHeight`, `CoordinatorState`, and the other
+//! per-round tables in this file) on a local backend, while streaming the
+//! large ones -- `Locator::ContributionFile` -- to an S3-compatible object
+//! store with transparent zstd compression. A large ceremony's contribution
+//! files are many gigabytes each; this lets the coordinator's own disk stay
+//! small while metadata reads (which happen on every request) never leave
+//! the machine.
+//!
+//! `storage.rs` (which would define the [`Storage`] trait and `Locator`
+//! itself) is absent from this tree, so this is written against the trait
+//! surface `coordinator.rs` actually exercises -- `insert`, `update`,
+//! `remove`, `exists`, `get`, `reader`, `to_path`, `to_locator` -- rather
+//! than against the trait definition directly. It also assumes an
+//! `s3 = { version = "0.3", features = ["blocking"] }` and `zstd` dependency,
+//! neither of which is in this tree's (absent) `Cargo.toml`.
+//!
+//! `to_path` on a `ContributionFile` locator returns a presigned URL rather
+//! than a local filesystem path, so the existing `add_contribution`/
+//! `verify_contribution` logging and the path handed back to participants
+//! for upload/download keep working unchanged.
+
+use crate::storage::{Locator, Object, Storage};
+use crate::CoordinatorError;
+
+use s3::bucket::Bucket;
+
+/// How long a presigned URL returned by `to_path` remains valid for.
+const PRESIGNED_URL_EXPIRY_SECONDS: u32 = 3600;
+
+/// Splits locators between a local backend and a remote, zstd-compressed
+/// object store, by delegating everything except `Locator::ContributionFile`
+/// to `local` unchanged.
+pub struct RemoteStorage<L: Storage> {
+    local: L,
+    bucket: Bucket,
+    /// zstd compression level applied to contribution objects before they're
+    /// uploaded; `0` asks zstd for its own default.
+    compression_level: i32,
+}
+
+impl<L: Storage> RemoteStorage<L> {
+    pub fn new(local: L, bucket: Bucket, compression_level: i32) -> Self {
+        Self {
+            local,
+            bucket,
+            compression_level,
+        }
+    }
+
+    /// Whether `locator` belongs on the remote object store rather than `local`.
+    fn is_remote(locator: &Locator) -> bool {
+        matches!(locator, Locator::ContributionFile(..))
+    }
+
+    /// The object store key a `Locator::ContributionFile` is stored under.
+    /// Only meaningful when [`RemoteStorage::is_remote`] holds for `locator`.
+    fn remote_key(&self, locator: &Locator) -> Result<String, CoordinatorError> {
+        self.local.to_path(locator)
+    }
+
+    fn compress(&self, bytes: &[u8]) -> Result<Vec<u8>, CoordinatorError> {
+        zstd::stream::encode_all(bytes, self.compression_level).map_err(|_| CoordinatorError::StorageUpdateFailed)
+    }
+
+    fn decompress(bytes: &[u8]) -> Result<Vec<u8>, CoordinatorError> {
+        zstd::stream::decode_all(bytes).map_err(|_| CoordinatorError::StorageReaderFailed)
+    }
+
+    /// Serializes `object` the same way `local` would, so the bytes this
+    /// backend compresses and uploads are exactly what a local `Storage`
+    /// implementation would have written to disk.
+    fn serialize(object: &Object) -> Result<Vec<u8>, CoordinatorError> {
+        serde_json::to_vec(object).map_err(|_| CoordinatorError::StorageUpdateFailed)
+    }
+
+    fn deserialize(bytes: &[u8]) -> Result<Object, CoordinatorError> {
+        serde_json::from_slice(bytes).map_err(|_| CoordinatorError::StorageReaderFailed)
+    }
+}
+
+impl<L: Storage> Storage for RemoteStorage<L> {
+    fn insert(&mut self, locator: Locator, object: Object) -> Result<(), CoordinatorError> {
+        if !Self::is_remote(&locator) {
+            return self.local.insert(locator, object);
+        }
+
+        let key = self.remote_key(&locator)?;
+        let compressed = self.compress(&Self::serialize(&object)?)?;
+        self.bucket
+            .put_object_blocking(&key, &compressed)
+            .map_err(|_| CoordinatorError::StorageUpdateFailed)?;
+        Ok(())
+    }
+
+    fn update(&mut self, locator: &Locator, object: Object) -> Result<(), CoordinatorError> {
+        if !Self::is_remote(locator) {
+            return self.local.update(locator, object);
+        }
+
+        let key = self.remote_key(locator)?;
+        let compressed = self.compress(&Self::serialize(&object)?)?;
+        self.bucket
+            .put_object_blocking(&key, &compressed)
+            .map_err(|_| CoordinatorError::StorageUpdateFailed)?;
+        Ok(())
+    }
+
+    fn remove(&mut self, locator: &Locator) -> Result<(), CoordinatorError> {
+        if !Self::is_remote(locator) {
+            return self.local.remove(locator);
+        }
+
+        let key = self.remote_key(locator)?;
+        self.bucket
+            .delete_object_blocking(&key)
+            .map_err(|_| CoordinatorError::StorageUpdateFailed)?;
+        Ok(())
+    }
+
+    fn exists(&self, locator: &Locator) -> bool {
+        if !Self::is_remote(locator) {
+            return self.local.exists(locator);
+        }
+
+        match self.remote_key(locator) {
+            Ok(key) => self.bucket.head_object_blocking(&key).is_ok(),
+            Err(_) => false,
+        }
+    }
+
+    fn get(&self, locator: &Locator) -> Result<Object, CoordinatorError> {
+        if !Self::is_remote(locator) {
+            return self.local.get(locator);
+        }
+
+        Self::deserialize(&self.reader(locator)?)
+    }
+
+    fn reader(&self, locator: &Locator) -> Result<Vec<u8>, CoordinatorError> {
+        if !Self::is_remote(locator) {
+            return self.local.reader(locator);
+        }
+
+        let key = self.remote_key(locator)?;
+        let (compressed, _status) = self
+            .bucket
+            .get_object_blocking(&key)
+            .map_err(|_| CoordinatorError::StorageReaderFailed)?;
+        Self::decompress(&compressed)
+    }
+
+    fn to_path(&self, locator: &Locator) -> Result<String, CoordinatorError> {
+        if !Self::is_remote(locator) {
+            return self.local.to_path(locator);
+        }
+
+        let key = self.remote_key(locator)?;
+        self.bucket
+            .presign_get(&key, PRESIGNED_URL_EXPIRY_SECONDS, None)
+            .map_err(|_| CoordinatorError::StorageLocatorFormatIncorrect)
+    }
+
+    fn to_locator(&self, path: &str) -> Result<Locator, CoordinatorError> {
+        // Presigned URLs aren't parsed back into `Locator`s -- only `local`
+        // ever hands a path to `to_locator`, so this always delegates.
+        self.local.to_locator(path)
+    }
+}
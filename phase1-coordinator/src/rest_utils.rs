@@ -2,6 +2,9 @@
 
 use crate::{
     authentication::{Production, Signature},
+    circuit_breaker::{CircuitBreaker, CircuitBreakerError},
+    coordinator_actor::CoordinatorActorHandle,
+    lookup_cache::LookupCache,
     objects::Task,
     s3::{S3Ctx, S3Error},
     storage::{ContributionLocator, ContributionSignatureLocator},
@@ -28,11 +31,22 @@ use rocket::{
 
 use anyhow::anyhow;
 
-use sha2::Sha256;
+use chrono::Utc;
+use sha2::{Sha256, Sha512};
+use sha3::Sha3_256;
 use subtle::ConstantTimeEq;
 
 use lazy_static::lazy_static;
-use std::{borrow::Cow, convert::TryFrom, io::Cursor, net::IpAddr, ops::Deref, sync::Arc, time::Duration};
+use std::{
+    borrow::Cow,
+    collections::HashMap,
+    convert::TryFrom,
+    io::Cursor,
+    net::IpAddr,
+    ops::Deref,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
 use thiserror::Error;
 use tracing::warn;
 
@@ -50,6 +64,58 @@ pub const PUBKEY_HEADER: &str = "ATS-Pubkey";
 pub const SIGNATURE_HEADER: &str = "ATS-Signature";
 pub const CONTENT_LENGTH_HEADER: &str = "Content-Length";
 pub const ACCESS_SECRET_HEADER: &str = "Access-Secret";
+pub const DATE_HEADER: &str = "Date";
+
+/// The lowercase form of `DATE_HEADER` as it appears in the `headers="..."`
+/// parameter of the `Signature` header.
+const DATE_HEADER_LOWER: &str = "date";
+
+/// How far the signed `Date` header may drift from the server's clock before
+/// a request is rejected as stale, bounding how long a captured request
+/// remains replayable.
+const MAX_CLOCK_SKEW: Duration = Duration::from_secs(300);
+
+lazy_static! {
+    /// Signatures seen within the last `MAX_CLOCK_SKEW` (the same window a
+    /// stale `Date` header is rejected on), keyed by the raw signature, so a
+    /// captured request/signature pair can't be resent within its validity
+    /// window. Entries older than the window are lazily evicted on insert.
+    static ref SEEN_SIGNATURES: Mutex<HashMap<String, Instant>> = Mutex::new(HashMap::new());
+
+    /// Caches each pubkey's current-contributor/banned/dropped status, so a
+    /// burst of requests from the same participant or verifier doesn't each
+    /// take a coordinator read lock to re-derive it.
+    static ref CONTRIBUTOR_STATUS_CACHE: LookupCache<String, ContributorStatusCheck> = LookupCache::with_defaults();
+
+    /// Trips after 3 consecutive verification failures, giving the default
+    /// verifier 30 seconds to recover before a probe call is let through
+    /// again, instead of retrying a broken dependency on every pending task.
+    static ref VERIFICATION_CIRCUIT_BREAKER: CircuitBreaker = CircuitBreaker::new(3, Duration::from_secs(30));
+    /// Trips after 3 consecutive S3 upload failures, with the same 30 second
+    /// recovery window.
+    static ref S3_CIRCUIT_BREAKER: CircuitBreaker = CircuitBreaker::new(3, Duration::from_secs(30));
+}
+
+/// The outcome of checking whether a pubkey is the current contributor, for
+/// caching in `CONTRIBUTOR_STATUS_CACHE`.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum ContributorStatusCheck {
+    Current,
+    Banned,
+    Dropped,
+    NotCurrent,
+}
+
+impl ContributorStatusCheck {
+    fn error_message(&self) -> &'static str {
+        match self {
+            ContributorStatusCheck::Current => "",
+            ContributorStatusCheck::Banned => "Participant has been banned from the ceremony",
+            ContributorStatusCheck::Dropped => "Participant has been dropped from the ceremony",
+            ContributorStatusCheck::NotCurrent => "Participant is not the current contributor",
+        }
+    }
+}
 
 lazy_static! {
     pub(crate) static ref HEALTH_PATH: String = match std::env::var("HEALTH_PATH") {
@@ -71,6 +137,10 @@ pub(crate) type Coordinator = Arc<RwLock<crate::Coordinator>>;
 pub enum ResponseError {
     #[error("Ceremony is over, no more contributions are allowed")]
     CeremonyIsOver,
+    #[error("The {0} dependency is currently unavailable, its circuit breaker is open")]
+    CircuitOpen(&'static str),
+    #[error("The coordinator is busy with another operation, please retry")]
+    CoordinatorBusy,
     #[error("Coordinator failed: {0}")]
     CoordinatorError(CoordinatorError),
     #[error("Contribution info is not valid: {0}")]
@@ -95,6 +165,8 @@ pub enum ResponseError {
     MissingSigningKey,
     #[error("Couldn't parse string to int: {0}")]
     ParseError(#[from] std::num::ParseIntError),
+    #[error("This request's signature has already been used and was rejected as a replay")]
+    ReplayedRequest,
     #[error("Thread panicked: {0}")]
     RuntimeError(#[from] task::JoinError),
     #[error("Error with S3: {0}")]
@@ -103,6 +175,8 @@ pub enum ResponseError {
     SerdeError(String),
     #[error("Error while terminating the ceremony: {0}")]
     ShutdownError(String),
+    #[error("The signed {0} header is outside the allowed clock skew")]
+    StaleRequest(&'static str),
     #[error("The provided token is currently being used in the ceremony")]
     TokenAlreadyInUse,
     #[error("The provided token has already been used in the ceremony")]
@@ -125,6 +199,8 @@ impl<'r> Responder<'r, 'static> for ResponseError {
         let response_code = match self {
             ResponseError::BlacklistedToken => Status::Unauthorized,
             ResponseError::CeremonyIsOver => Status::Unauthorized,
+            ResponseError::CircuitOpen(_) => Status::ServiceUnavailable,
+            ResponseError::CoordinatorBusy => Status::ServiceUnavailable,
             ResponseError::InvalidHeader(_) => Status::BadRequest,
             ResponseError::InvalidSecret => Status::Unauthorized,
             ResponseError::InvalidSignature => Status::BadRequest,
@@ -133,7 +209,9 @@ impl<'r> Responder<'r, 'static> for ResponseError {
             ResponseError::MissingRequiredHeader(h) if h == CONTENT_LENGTH_HEADER => Status::LengthRequired,
             ResponseError::MissingRequiredHeader(_) => Status::BadRequest,
             ResponseError::MissingSigningKey => Status::BadRequest,
+            ResponseError::ReplayedRequest => Status::Conflict,
             ResponseError::SerdeError(_) => Status::UnprocessableEntity,
+            ResponseError::StaleRequest(_) => Status::BadRequest,
             ResponseError::TokenAlreadyInUse => Status::Unauthorized,
             ResponseError::UnauthorizedParticipant(_, _, _) => Status::Unauthorized,
             ResponseError::WrongDigestEncoding(_) => Status::BadRequest,
@@ -195,34 +273,102 @@ pub fn io_error(req: &Request) -> ResponseError {
     ResponseError::IoError(message.to_owned())
 }
 
+/// The hash function a request's body digest was computed with. The `Digest`
+/// header's algorithm prefix (`sha-256=`, `sha-512=`, `sha3-256=`) lets a
+/// client pick whichever is cheapest or most future-proof for it, instead of
+/// the server hard-coding SHA-256.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DigestAlgorithm {
+    Sha256,
+    Sha512,
+    Sha3_256,
+}
+
+impl DigestAlgorithm {
+    /// The prefix used in the `Digest` header, e.g. `"sha-256"`.
+    fn as_header_prefix(&self) -> &'static str {
+        match self {
+            DigestAlgorithm::Sha256 => "sha-256",
+            DigestAlgorithm::Sha512 => "sha-512",
+            DigestAlgorithm::Sha3_256 => "sha3-256",
+        }
+    }
+
+    fn from_header_prefix(prefix: &str) -> Result<Self> {
+        match prefix {
+            "sha-256" => Ok(DigestAlgorithm::Sha256),
+            "sha-512" => Ok(DigestAlgorithm::Sha512),
+            "sha3-256" => Ok(DigestAlgorithm::Sha3_256),
+            _ => Err(ResponseError::InvalidHeader(BODY_DIGEST_HEADER)),
+        }
+    }
+
+    /// Hashes `body` and base64-encodes the digest, matching the encoding
+    /// `RequestContent` expects on the wire.
+    fn digest(&self, body: &[u8]) -> String {
+        match self {
+            DigestAlgorithm::Sha256 => {
+                let mut hasher = Sha256::new();
+                hasher.update(body);
+                base64::encode(hasher.finalize())
+            }
+            DigestAlgorithm::Sha512 => {
+                let mut hasher = Sha512::new();
+                hasher.update(body);
+                base64::encode(hasher.finalize())
+            }
+            DigestAlgorithm::Sha3_256 => {
+                let mut hasher = Sha3_256::new();
+                hasher.update(body);
+                base64::encode(hasher.finalize())
+            }
+        }
+    }
+}
+
+impl Default for DigestAlgorithm {
+    fn default() -> Self {
+        DigestAlgorithm::Sha256
+    }
+}
+
 /// Content info
 pub struct RequestContent<'a> {
     len: usize,
     digest: Cow<'a, str>,
+    algorithm: DigestAlgorithm,
 }
 
 impl<'a> RequestContent<'a> {
     pub fn new<T>(len: usize, digest: T) -> Self
+    where
+        T: AsRef<[u8]>,
+    {
+        Self::with_algorithm(len, digest, DigestAlgorithm::default())
+    }
+
+    pub fn with_algorithm<T>(len: usize, digest: T, algorithm: DigestAlgorithm) -> Self
     where
         T: AsRef<[u8]>,
     {
         Self {
             len,
             digest: base64::encode(digest).into(),
+            algorithm,
         }
     }
 
     /// Returns struct correctly formatted for the http header
     pub fn to_header(&self) -> (usize, String) {
-        (self.len, format!("sha-256={}", self.digest))
+        (self.len, format!("{}={}", self.algorithm.as_header_prefix(), self.digest))
     }
 
     /// Constructs from request's headers
     fn try_from_header(len: &str, digest: &'a str) -> Result<Self> {
-        let digest = digest
+        let (prefix, digest) = digest
             .split_once('=')
-            .ok_or(ResponseError::InvalidHeader(BODY_DIGEST_HEADER))?
-            .1;
+            .ok_or(ResponseError::InvalidHeader(BODY_DIGEST_HEADER))?;
+        let algorithm = DigestAlgorithm::from_header_prefix(prefix)?;
 
         // Check encoding
         base64::decode(digest)?;
@@ -233,25 +379,82 @@ impl<'a> RequestContent<'a> {
         Ok(Self {
             len,
             digest: digest.into(),
+            algorithm,
+        })
+    }
+}
+
+/// The pseudo-header component standing in for the request's method and path,
+/// as defined by the HTTP Message Signatures draft (draft-cavage / RFC 9421).
+const REQUEST_TARGET: &str = "(request-target)";
+
+/// A parsed `Signature` header: `keyId="...", algorithm="...", headers="...", signature="..."`.
+struct SignatureParams<'r> {
+    key_id: &'r str,
+    /// The signed header set, in the order they must be assembled into the
+    /// signing string, e.g. `["(request-target)", "date", "content-length", "digest"]`.
+    headers: Vec<&'r str>,
+    signature: Cow<'r, str>,
+}
+
+impl<'r> SignatureParams<'r> {
+    /// Parses the structured `Signature` header value.
+    ///
+    /// Expected shape: `keyId="<pubkey>",algorithm="<algo>",headers="(request-target) date content-length digest",signature="<base64>"`.
+    fn parse(raw: &'r str) -> Result<Self> {
+        let mut key_id = None;
+        let mut headers = None;
+        let mut signature = None;
+
+        for part in raw.split(',') {
+            let (name, value) = part
+                .split_once('=')
+                .ok_or(ResponseError::InvalidHeader(SIGNATURE_HEADER))?;
+            let value = value.trim().trim_matches('"');
+
+            match name.trim() {
+                "keyId" => key_id = Some(value),
+                "headers" => headers = Some(value.split(' ').filter(|h| !h.is_empty()).collect()),
+                "signature" => signature = Some(Cow::Borrowed(value)),
+                // "algorithm" and any future parameters are not load-bearing for verification.
+                _ => {}
+            }
+        }
+
+        Ok(Self {
+            key_id: key_id.ok_or(ResponseError::InvalidHeader(SIGNATURE_HEADER))?,
+            headers: headers.ok_or(ResponseError::InvalidHeader(SIGNATURE_HEADER))?,
+            signature: signature.ok_or(ResponseError::InvalidHeader(SIGNATURE_HEADER))?,
         })
     }
 }
 
 /// The headers involved in the signature of the request.
-#[derive(Default)]
 pub struct SignatureHeaders<'r> {
     pub pubkey: &'r str,
     pub content: Option<RequestContent<'r>>,
     pub signature: Option<Cow<'r, str>>,
+    /// The declared signed header set and the raw values of the non-pseudo
+    /// headers among them, so the signing string can be reconstructed in the
+    /// exact order and form the client signed.
+    components: Vec<(&'r str, Cow<'r, str>)>,
 }
 
 impl<'r> SignatureHeaders<'r> {
-    /// Produces the message on which to compute the signature
+    /// Produces the canonical signing string: one line per declared signed
+    /// component, in the declared order, joined by `\n` — the pseudo-component
+    /// `(request-target)` rendered as `"{lowercased-method} {path-and-query}"`,
+    /// and each real header rendered as `"{lowercase-name}: {value}"`.
     pub fn to_string(&self) -> Cow<'_, str> {
-        match &self.content {
-            Some(content) => format!("{}{}{}", self.pubkey, content.len, content.digest).into(),
-            None => self.pubkey.into(),
-        }
+        self.components
+            .iter()
+            .map(|(name, value)| match *name {
+                REQUEST_TARGET => value.to_string(),
+                name => format!("{}: {}", name, value),
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+            .into()
     }
 
     pub fn new(pubkey: &'r str, content: Option<RequestContent<'r>>, signature: Option<Cow<'r, str>>) -> Self {
@@ -259,15 +462,56 @@ impl<'r> SignatureHeaders<'r> {
             pubkey,
             content,
             signature,
+            components: Vec::new(),
         }
     }
 
     fn try_verify_signature(&self) -> Result<bool> {
         match &self.signature {
-            Some(sig) => Ok(Production.verify(self.pubkey, &self.to_string(), &sig)),
+            Some(sig) => Ok(Production.verify(self.pubkey, &self.to_string(), sig)),
             None => Err(ResponseError::MissingSigningKey),
         }
     }
+
+    /// The raw value of the signed `Date` header, as reconstructed into the
+    /// signing string's component list.
+    fn date(&self) -> Option<&str> {
+        self.components
+            .iter()
+            .find(|(name, _)| *name == DATE_HEADER_LOWER)
+            .map(|(_, value)| value.as_ref())
+    }
+}
+
+/// Rejects requests whose signed `Date` header has drifted more than
+/// `MAX_CLOCK_SKEW` from the server's clock, either stale or from the future.
+fn check_freshness(date: &str) -> Result<()> {
+    let signed_at = chrono::DateTime::parse_from_rfc2822(date)
+        .map_err(|_| ResponseError::InvalidHeader(DATE_HEADER))?
+        .with_timezone(&Utc);
+    let skew = (Utc::now() - signed_at).abs();
+
+    match skew.to_std() {
+        Ok(skew) if skew <= MAX_CLOCK_SKEW => Ok(()),
+        _ => Err(ResponseError::StaleRequest(DATE_HEADER)),
+    }
+}
+
+/// Rejects a signature that has already been accepted once within
+/// `MAX_CLOCK_SKEW` (the same window a stale `Date` is rejected on, since a
+/// signature can't be replayed usefully once its `Date` falls outside it).
+/// Also opportunistically evicts cache entries that have aged out.
+fn check_not_replayed(signature: &str) -> Result<()> {
+    let mut seen = SEEN_SIGNATURES.lock().expect("Replay cache lock poisoned");
+    let now = Instant::now();
+
+    seen.retain(|_, seen_at| now.duration_since(*seen_at) <= MAX_CLOCK_SKEW);
+
+    if seen.insert(signature.to_owned(), now).is_some() {
+        return Err(ResponseError::ReplayedRequest);
+    }
+
+    Ok(())
 }
 
 impl<'r> TryFrom<&'r Request<'_>> for SignatureHeaders<'r> {
@@ -275,31 +519,72 @@ impl<'r> TryFrom<&'r Request<'_>> for SignatureHeaders<'r> {
 
     fn try_from(request: &'r Request<'_>) -> std::result::Result<Self, Self::Error> {
         let headers = request.headers();
-        let mut body: Option<RequestContent> = None;
 
-        let pubkey = headers
-            .get_one(PUBKEY_HEADER)
-            .ok_or(ResponseError::InvalidHeader(PUBKEY_HEADER))?;
-        let sig = headers
+        let raw_signature = headers
             .get_one(SIGNATURE_HEADER)
             .ok_or(ResponseError::InvalidHeader(SIGNATURE_HEADER))?;
+        let params = SignatureParams::parse(raw_signature)?;
 
-        // If post request, also get the hash of body from header (if any and if base64 encoded)
-        if request.method() == rocket::http::Method::Post {
-            if let Some(s) = headers.get_one(BODY_DIGEST_HEADER) {
-                let content_length = headers
-                    .get_one(CONTENT_LENGTH_HEADER)
-                    .ok_or(ResponseError::InvalidHeader(CONTENT_LENGTH_HEADER))?;
-                let content = RequestContent::try_from_header(content_length, s)?;
+        // `(request-target)` must always be part of the signed set, so a
+        // signature can never be replayed against a different method/path.
+        if !params.headers.contains(&REQUEST_TARGET) {
+            return Err(ResponseError::InvalidHeader(SIGNATURE_HEADER));
+        }
 
-                body = Some(content);
-            }
+        // POST bodies must bind the digest into the signature as well.
+        if request.method() == rocket::http::Method::Post && !params.headers.contains(&BODY_DIGEST_HEADER_LOWER) {
+            return Err(ResponseError::InvalidHeader(BODY_DIGEST_HEADER));
+        }
+
+        // `date` must always be signed: it's what lets `verify_signature` bound
+        // how long a captured request stays replayable.
+        if !params.headers.contains(&DATE_HEADER_LOWER) {
+            return Err(ResponseError::InvalidHeader(DATE_HEADER));
         }
 
-        Ok(SignatureHeaders::new(pubkey, body, Some(sig.into())))
+        let mut components = Vec::with_capacity(params.headers.len());
+        let mut content: Option<RequestContent> = None;
+
+        for component in &params.headers {
+            let value: Cow<str> = match *component {
+                REQUEST_TARGET => format!(
+                    "{} {}",
+                    request.method().as_str().to_lowercase(),
+                    request.uri()
+                )
+                .into(),
+                BODY_DIGEST_HEADER_LOWER => {
+                    let digest_header = headers
+                        .get_one(BODY_DIGEST_HEADER)
+                        .ok_or(ResponseError::MissingRequiredHeader(BODY_DIGEST_HEADER))?;
+                    let content_length = headers
+                        .get_one(CONTENT_LENGTH_HEADER)
+                        .ok_or(ResponseError::InvalidHeader(CONTENT_LENGTH_HEADER))?;
+                    content = Some(RequestContent::try_from_header(content_length, digest_header)?);
+                    digest_header.into()
+                }
+                name => headers
+                    .get_one(name)
+                    // The declared header name is only known at request time, so it can't be
+                    // threaded through `MissingRequiredHeader`'s `&'static str`; the signature
+                    // header itself is the only static context we can point back to.
+                    .ok_or(ResponseError::InvalidHeader(SIGNATURE_HEADER))?
+                    .into(),
+            };
+
+            components.push((*component, value));
+        }
+
+        let mut signature_headers = SignatureHeaders::new(params.key_id, content, Some(params.signature));
+        signature_headers.components = components;
+        Ok(signature_headers)
     }
 }
 
+/// The lowercase form of `BODY_DIGEST_HEADER` as it appears in the `headers="..."`
+/// parameter of the `Signature` header (header names there are always lowercase).
+const BODY_DIGEST_HEADER_LOWER: &str = "digest";
+
 trait VerifySignature<'r> {
     // Workaround to implement a single method on a foreign type instead of newtype pattern
     fn verify_signature(&'r self) -> Result<&str>;
@@ -310,10 +595,18 @@ impl<'r> VerifySignature<'r> for Request<'_> {
     fn verify_signature(&'r self) -> Result<&str> {
         let headers = SignatureHeaders::try_from(self)?;
 
-        match headers.try_verify_signature()? {
-            true => Ok(headers.pubkey),
-            false => Err(ResponseError::InvalidSignature),
+        if !headers.try_verify_signature()? {
+            return Err(ResponseError::InvalidSignature);
         }
+
+        // Only check freshness/replay once the signature itself is known to be
+        // genuine, so an attacker can't burn a legitimate signature out of the
+        // replay cache by sending it with a mismatched body.
+        let date = headers.date().ok_or(ResponseError::InvalidHeader(DATE_HEADER))?;
+        check_freshness(date)?;
+        check_not_replayed(headers.signature.as_deref().unwrap_or_default())?;
+
+        Ok(headers.pubkey)
     }
 }
 
@@ -406,20 +699,25 @@ impl<'r> FromRequest<'r> for CurrentContributor {
             .expect("Managed state should always be retrievable");
         let participant = Participant::new_contributor(pubkey);
 
-        let read_lock = coordinator.read().await;
-        if !read_lock.is_current_contributor(&participant) {
-            // Cache error data for the error catcher
-            let error_msg = {
-                if read_lock.is_banned_participant(&participant) {
-                    String::from("Participant has been banned from the ceremony")
+        let status = CONTRIBUTOR_STATUS_CACHE
+            .get_or_compute(pubkey.to_owned(), || async {
+                let read_lock = coordinator.read().await;
+                if read_lock.is_current_contributor(&participant) {
+                    ContributorStatusCheck::Current
+                } else if read_lock.is_banned_participant(&participant) {
+                    ContributorStatusCheck::Banned
                 } else if read_lock.is_dropped_participant(&participant) {
-                    String::from("Participant has been dropped from the ceremony")
+                    ContributorStatusCheck::Dropped
                 } else {
-                    String::from("Participant is not the current contributor")
+                    ContributorStatusCheck::NotCurrent
                 }
-            };
-            drop(read_lock);
+            })
+            .await;
+
+        if status != ContributorStatusCheck::Current {
+            let error_msg = status.error_message().to_string();
 
+            // Cache error data for the error catcher
             request.local_cache(|| participant.clone());
             request.local_cache(|| (request.uri().to_string(), error_msg.clone()));
 
@@ -560,9 +858,7 @@ impl<'r, T: DeserializeOwned> FromData<'r> for LazyJson<T> {
             }
         };
 
-        let mut hasher = Sha256::new();
-        hasher.update(&body);
-        let digest = base64::encode(hasher.finalize());
+        let digest = expected_content.algorithm.digest(&body);
         if digest != expected_content.digest {
             // Cache error data for the error catcher
             req.local_cache(|| (expected_digest.to_owned(), expected_content.digest.to_string()));
@@ -648,75 +944,172 @@ pub(crate) async fn token_check(coordinator: Coordinator, token: &str) -> Result
     Ok((cohort + 1) as u64)
 }
 
-/// Performs the verification of the pending contributions
-///
-/// # Cancel safety
-///
-/// https://docs.rs/tokio/latest/tokio/macro.select.html#cancellation-safety
+/// One pending verification's outcome, keyed by the participant whose
+/// contribution it was, so a failure only implicates that participant.
+struct VerificationOutcome {
+    participant: Participant,
+    result: Result<()>,
+}
+
+/// Verifies every pending contribution concurrently, and returns the
+/// resulting contributions summary.
 ///
-/// Because of the use of [`tokio::sync::rwlock::RwLock::write_owned`], which is not cancel safe, and a spawned blocking
-/// task, which cannot be cancelled, this function is not cancel safe.
-pub async fn perform_verify_chunks(coordinator: Coordinator, s3_ctx: &S3Ctx) -> Result<()> {
-    // Get all the pending verifications, loop on each one of them and perform verification
-    // Technically, since we don't chunk contributions and we only have one contribution per round, we will always get
-    // one pending verification at max.
-    let mut write_lock = coordinator.write_owned().await;
+/// Runs exclusively on the [`coordinator_actor`](`crate::coordinator_actor`)
+/// task in response to a `VerifyPending` command; callers go through
+/// [`CoordinatorActorHandle::verify_pending`](`crate::coordinator_actor::CoordinatorActorHandle::verify_pending`)
+/// instead of calling this directly.
+pub(crate) async fn verify_pending_contributions(coordinator: Coordinator) -> Result<Vec<u8>> {
+    // If the default verifier has been failing, don't pile more work onto it;
+    // let it recover for a while instead of banning a string of participants
+    // whose contributions never actually got a fair verification.
+    if !VERIFICATION_CIRCUIT_BREAKER.allow_call() {
+        return Err(ResponseError::CircuitOpen("verification"));
+    }
+
+    // Double-checked locking: start with a read guard, since the common case
+    // (every pending contribution verifies cleanly) never mutates shared
+    // state until the final summary read below. Only escalate to a write
+    // guard if a contribution actually fails and a ban is required.
+    let read_lock = coordinator.clone().read_owned().await;
+    let pending = read_lock.get_pending_verifications().to_owned();
 
     // NOTE: we are going to rely on the single default verifier built in the coordinator itself,
     //  no external verifiers
-    let contributions_info = task::spawn_blocking(move || -> Result<Vec<u8>> {
-        for (task, _) in write_lock.get_pending_verifications().to_owned() {
-            if let Err(e) = write_lock.default_verify(&task) {
-                warn!("Error while verifying a contribution: {}. Restarting the round...", e);
-                // FIXME: the verify_masp function may panic but the program doesn't shut down because we are executing it on a separate thread. It would be better though to make that function return a Result instead of panicking. Revert of round should be moved inside default_verify
-
-                // Get the participant who produced the contribution
-                let finished_contributor = write_lock
-                    .state()
-                    .current_round_finished_contributors()
-                    .unwrap()
-                    .first()
-                    .unwrap()
-                    .clone();
-
-                // Reset the round to prevent a coordinator stall (the corrupted contribution is not automatically dropped)
-                write_lock
-                    .reset_round()
-                    .map_err(|e| ResponseError::CoordinatorError(e))?;
-
-                // Ban the participant who produced the invalid contribution. Must be banned after the reset beacuse one can't ban a finished contributor
-                write_lock
-                    .ban_participant(&finished_contributor)
-                    .map_err(|e| ResponseError::CoordinatorError(e))?;
+    let mut verifications = task::JoinSet::new();
+    for (task, participant) in pending {
+        let coordinator = coordinator.clone();
+        verifications.spawn_blocking(move || {
+            let read_lock = coordinator.blocking_read();
+
+            // `default_verify` (and the `verify_masp` it calls into) isn't
+            // guaranteed not to panic on a malformed contribution; an
+            // uncaught panic on this blocking thread would silently kill
+            // the worker rather than the process, leaving the task stuck
+            // pending forever. Catch it here and fold it into the same
+            // `Err` path as a clean verification failure, so both are
+            // handled uniformly by the ban logic below.
+            let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| read_lock.default_verify(&task)))
+                .unwrap_or_else(|panic| {
+                    let message = panic
+                        .downcast_ref::<&str>()
+                        .map(|s| s.to_string())
+                        .or_else(|| panic.downcast_ref::<String>().cloned())
+                        .unwrap_or_else(|| "verification panicked with a non-string payload".to_string());
+                    Err(CoordinatorError::Error(anyhow!("Verification panicked: {}", message)))
+                })
+                .map_err(ResponseError::CoordinatorError);
+
+            VerificationOutcome { participant, result }
+        });
+    }
+
+    // Drain results as they complete, under the outer read guard: the
+    // inner per-task reads above and this one can all proceed concurrently.
+    let mut failed = Vec::new();
+    while let Some(outcome) = verifications.join_next().await {
+        let VerificationOutcome { participant, result } = outcome.map_err(ResponseError::RuntimeError)?;
+
+        match result {
+            Ok(()) => VERIFICATION_CIRCUIT_BREAKER.record_success(),
+            Err(e) => {
+                warn!(
+                    "Error while verifying {}'s contribution: {}. Banning the participant...",
+                    participant, e
+                );
+                VERIFICATION_CIRCUIT_BREAKER.record_failure();
+                failed.push(participant);
             }
         }
+    }
 
-        write_lock
-            .storage()
-            .get_contributions_summary()
-            .map_err(|e| ResponseError::CoordinatorError(e))
-    })
-    .await??;
+    // Escalate to a write guard only if there's actually something to ban —
+    // the hot, all-valid path never pays for a write lock. Ban only the
+    // participants whose contribution actually failed, rather than
+    // resetting the whole round for one unrelated bad contribution.
+    let read_lock = if failed.is_empty() {
+        read_lock
+    } else {
+        drop(read_lock);
+        let mut write_lock = coordinator.write_owned().await;
+        for participant in &failed {
+            write_lock
+                .ban_participant(participant)
+                .map_err(ResponseError::CoordinatorError)?;
+        }
 
-    // Upload json file to S3
-    s3_ctx
-        .upload_contributions_info(contributions_info)
-        .await
-        .map_err(|e| ResponseError::CoordinatorError(CoordinatorError::Error(anyhow!(e.to_string()))))
+        // Downgrade back to a read guard to read the final summary without
+        // releasing the lock and racing another writer for it.
+        write_lock.downgrade()
+    };
+
+    read_lock
+        .storage()
+        .get_contributions_summary()
+        .map_err(ResponseError::CoordinatorError)
 }
 
-/// Performs the update of the [Coordinator](`crate::Coordinator`)
+/// Verifies pending contributions via the coordinator actor and uploads the
+/// resulting contributions summary to S3.
 ///
 /// # Cancel safety
 ///
-/// https://docs.rs/tokio/latest/tokio/macro.select.html#cancellation-safety
+/// Cancel safe: the coordinator mutation runs to completion on the
+/// [`coordinator_actor`](`crate::coordinator_actor`) task regardless of
+/// whether this future is awaited to completion, since awaiting a
+/// `oneshot::Receiver` only ever drops the receiver, not the sender's side of
+/// the work. Only the S3 upload that follows is on this future's own task,
+/// and `S3Ctx::upload_contributions_info` is expected to be retried safely.
+pub async fn perform_verify_chunks(actor: &CoordinatorActorHandle, s3_ctx: &S3Ctx) -> Result<()> {
+    let contributions_info = actor.verify_pending().await?;
+
+    // Upload json file to S3, through the same breaker pattern: stop hitting
+    // a degraded S3 on every verification pass once it's failed a few times
+    // in a row.
+    S3_CIRCUIT_BREAKER
+        .call(|| s3_ctx.upload_contributions_info(contributions_info))
+        .await
+        .map_err(|e| match e {
+            CircuitBreakerError::Open => ResponseError::CircuitOpen("s3"),
+            CircuitBreakerError::Inner(e) => ResponseError::CoordinatorError(CoordinatorError::Error(anyhow!(e.to_string()))),
+        })
+}
+
+/// Drives one update cycle of the [Coordinator](`crate::Coordinator`) via the
+/// coordinator actor.
+///
+/// # Cancel safety
 ///
-/// Because of the use of [`tokio::sync::rwlock::RwLock::write_owned`], which is not cancel safe, and a spawned blocking
-/// task, which cannot be cancelled, this function is not cancel safe.
-pub async fn perform_coordinator_update(coordinator: Coordinator) -> Result<()> {
-    let mut write_lock = coordinator.write_owned().await;
+/// Cancel safe, for the same reason as [`perform_verify_chunks`]: the
+/// `write_owned` lock and blocking update run on the
+/// [`coordinator_actor`](`crate::coordinator_actor`) task, not on this
+/// future, so dropping this future before it resolves cannot leave the
+/// coordinator mid-update with a stranded lock.
+pub async fn perform_coordinator_update(actor: &CoordinatorActorHandle) -> Result<()> {
+    actor.update().await
+}
+
+/// Non-blocking variant of [`perform_coordinator_update`], for callers that
+/// want immediate backpressure rather than queuing behind the coordinator
+/// actor's command channel: if the coordinator is currently locked (an
+/// update or verification already in progress), this fails fast with
+/// [`ResponseError::CoordinatorBusy`] instead of waiting for it to free up.
+pub async fn try_perform_coordinator_update(coordinator: &Coordinator) -> Result<()> {
+    let write_lock = coordinator
+        .clone()
+        .try_write_owned()
+        .map_err(|_| ResponseError::CoordinatorBusy)?;
 
     task::spawn_blocking(move || write_lock.update())
-        .await?
-        .map_err(|e| ResponseError::CoordinatorError(e))
+        .await
+        .map_err(ResponseError::RuntimeError)?
+        .map_err(ResponseError::CoordinatorError)
+}
+
+/// Non-blocking variant of [`perform_verify_chunks`]'s pending-verification
+/// count check, for callers that want to know *before* queuing a
+/// `VerifyPending` command whether there's even anything to do, without
+/// waiting behind a contended lock to find out.
+pub async fn try_has_pending_verifications(coordinator: &Coordinator) -> Result<bool> {
+    let read_lock = coordinator.try_read().map_err(|_| ResponseError::CoordinatorBusy)?;
+    Ok(!read_lock.get_pending_verifications().is_empty())
 }
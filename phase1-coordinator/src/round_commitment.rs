@@ -0,0 +1,110 @@
+//! A fixed-size Merkle commitment over a finished round's per-chunk
+//! contributions, built once by `Coordinator::aggregate_contributions` when
+//! a round is finalized, so a contributor can later prove in `O(log n)`
+//! that their chunk's final verified contribution was committed to.
+//!
+//! Unlike [`crate::merkle_transcript::MerkleTranscript`] -- which accumulates
+//! leaves one at a time over the course of a round -- the leaf set here
+//! (one hash per chunk) is known in full up front, so this is an ordinary
+//! binary Merkle tree: sibling pairs are combined bottom-up with
+//! `calculate_hash(left || right)`, and whenever a level has an odd number
+//! of nodes its last node is paired with a duplicate of itself, rather than
+//! left unpaired as [`MerkleTranscript`]'s "peaks" are.
+
+use crate::merkle_transcript::Hash;
+use serde::{Deserialize, Serialize};
+use setup_utils::calculate_hash;
+
+/// A completed Merkle commitment over a round's per-chunk contributions.
+/// `levels[0]` is the leaves (one per chunk, in chunk ID order); each
+/// subsequent level holds the hashes combining the level beneath it.
+///
+/// Persisted via `Locator::RoundCommitment(u64)`/
+/// `Object::RoundCommitment(RoundCommitment)` on the storage side, alongside
+/// the round file, for the same reason `ContributionTranscript` is stored
+/// independently of `CoordinatorState`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Default)]
+pub struct RoundCommitment {
+    levels: Vec<Vec<Hash>>,
+}
+
+impl RoundCommitment {
+    fn combine(left: &Hash, right: &Hash) -> Hash {
+        let mut bytes = Vec::with_capacity(left.len() + right.len());
+        bytes.extend_from_slice(left);
+        bytes.extend_from_slice(right);
+        calculate_hash(&bytes)
+    }
+
+    /// Builds the commitment for `leaves` (one hash per chunk, in chunk ID
+    /// order). Returns an empty commitment, with no root, if `leaves` is empty.
+    pub fn new(leaves: Vec<Hash>) -> Self {
+        if leaves.is_empty() {
+            return Self::default();
+        }
+
+        let mut levels = vec![leaves];
+        while levels.last().expect("levels is never empty").len() > 1 {
+            let previous = levels.last().expect("levels is never empty");
+            let mut next = Vec::with_capacity((previous.len() + 1) / 2);
+
+            let mut i = 0;
+            while i < previous.len() {
+                let left = &previous[i];
+                // An odd node out is paired with a duplicate of itself,
+                // rather than promoted unpaired to the next level.
+                let right = previous.get(i + 1).unwrap_or(left);
+                next.push(Self::combine(left, right));
+                i += 2;
+            }
+
+            levels.push(next);
+        }
+
+        Self { levels }
+    }
+
+    /// The root of this commitment, or `None` if no leaves were committed.
+    pub fn root(&self) -> Option<Hash> {
+        self.levels.last()?.first().cloned()
+    }
+
+    /// Returns the authentication path for the leaf at `chunk_id`: the
+    /// ordered sibling hashes a caller combines with the leaf, bottom-up,
+    /// to re-derive [`RoundCommitment::root`]. Returns `None` if `chunk_id`
+    /// is out of range.
+    pub fn proof(&self, chunk_id: u64) -> Option<Vec<Hash>> {
+        let mut index = usize::try_from(chunk_id).ok()?;
+        if self.levels.is_empty() || index >= self.levels[0].len() {
+            return None;
+        }
+
+        let mut steps = Vec::new();
+        for level in &self.levels[..self.levels.len() - 1] {
+            let sibling_index = if index % 2 == 0 { index + 1 } else { index - 1 };
+            // When `index` was the odd node out, its sibling in the tree is
+            // a duplicate of itself, not the (nonexistent) node at `index + 1`.
+            let sibling = level.get(sibling_index).unwrap_or(&level[index]);
+            steps.push(sibling.clone());
+            index /= 2;
+        }
+
+        Some(steps)
+    }
+
+    /// Recomputes the root implied by the leaf at `chunk_id` and its
+    /// authentication `proof`, for comparison against a previously recorded
+    /// [`RoundCommitment::root`].
+    pub fn verify(leaf: &Hash, chunk_id: u64, proof: &[Hash]) -> Hash {
+        let mut current = leaf.clone();
+        let mut index = chunk_id as usize;
+        for sibling in proof {
+            current = match index % 2 == 0 {
+                true => Self::combine(&current, sibling),
+                false => Self::combine(sibling, &current),
+            };
+            index /= 2;
+        }
+        current
+    }
+}
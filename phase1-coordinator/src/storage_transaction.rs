@@ -0,0 +1,121 @@
+//! A small batch of `Storage` writes that commit together or not at all,
+//! for the coordinator-level write sequences in `next_round` and
+//! `aggregate_contributions` that update more than one locator to reflect a
+//! single logical change -- e.g. inserting the new round's `RoundState` and
+//! advancing `RoundHeight` to point at it. Without batching, a failure
+//! between the two leaves one locator updated and the other stale.
+//!
+//! This only protects sequences of direct `storage.insert`/`update`/`remove`
+//! calls made from `coordinator.rs` itself. The bulk of `next_round`'s and
+//! `aggregate_contributions`'s writes happen inside `Initialization::run`,
+//! `Computation::run`, and `Aggregation::run`, which write to storage
+//! directly as they run and aren't staged through this batch -- routing
+//! those through a transaction too would mean changing those commands to
+//! accept a [`StorageTransaction`] instead of writing to `Storage` directly,
+//! which is a larger change than this one makes.
+//!
+//! Commits are atomic only within the current process: if a write partway
+//! through a transaction fails, every write already applied by this
+//! transaction is undone before the error is returned, so a caller never
+//! observes the transaction half-applied. This does not protect against a
+//! crash during `commit` itself -- `wal.rs`'s write-ahead log is the
+//! mechanism for surviving that, for the one value (`CoordinatorState`) it
+//! covers.
+
+use crate::coordinator::CoordinatorError;
+use crate::storage::{Locator, Object, StorageLock};
+use tracing::error;
+
+/// One write staged into a [`StorageTransaction`].
+enum StorageOp {
+    Insert(Object),
+    Update(Object),
+    Remove,
+}
+
+/// How to undo a [`StorageOp`] that was already applied, captured from the
+/// locator's state immediately before that write was made.
+enum UndoOp {
+    Remove,
+    Restore(Object),
+}
+
+/// A batch of writes to distinct locators that `commit` applies as a unit,
+/// rolling back every write it already made if a later one fails.
+#[derive(Default)]
+pub struct StorageTransaction {
+    ops: Vec<(Locator, StorageOp)>,
+}
+
+impl StorageTransaction {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Stages inserting `object` at `locator`.
+    pub fn insert(&mut self, locator: Locator, object: Object) {
+        self.ops.push((locator, StorageOp::Insert(object)));
+    }
+
+    /// Stages overwriting `locator` with `object`.
+    pub fn update(&mut self, locator: Locator, object: Object) {
+        self.ops.push((locator, StorageOp::Update(object)));
+    }
+
+    /// Stages removing `locator`.
+    pub fn remove(&mut self, locator: Locator) {
+        self.ops.push((locator, StorageOp::Remove));
+    }
+
+    /// Applies every staged write to `storage`, in the order staged. If any
+    /// write fails, every write already applied by this transaction is
+    /// undone (in reverse order) before the triggering error is returned.
+    pub fn commit(self, storage: &mut StorageLock) -> Result<(), CoordinatorError> {
+        let mut applied = Vec::with_capacity(self.ops.len());
+
+        for (locator, op) in self.ops {
+            let undo = match storage.exists(&locator) {
+                true => match storage.get(&locator) {
+                    Ok(object) => UndoOp::Restore(object),
+                    Err(error) => {
+                        Self::rollback(storage, applied);
+                        return Err(error);
+                    }
+                },
+                false => UndoOp::Remove,
+            };
+
+            let result = match &op {
+                StorageOp::Insert(object) => storage.insert(locator.clone(), object.clone()),
+                StorageOp::Update(object) => storage.update(&locator, object.clone()),
+                StorageOp::Remove => storage.remove(&locator),
+            };
+
+            match result {
+                Ok(_) => applied.push((locator, undo)),
+                Err(error) => {
+                    Self::rollback(storage, applied);
+                    return Err(error);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Undoes `applied` writes in reverse order. A failure while rolling
+    /// back is logged rather than propagated -- the original error that
+    /// triggered the rollback is what the caller needs to see.
+    fn rollback(storage: &mut StorageLock, applied: Vec<(Locator, UndoOp)>) {
+        for (locator, undo) in applied.into_iter().rev() {
+            let path = storage.to_path(&locator).unwrap_or_else(|_| "<unknown locator>".to_string());
+            let result = match undo {
+                UndoOp::Remove => storage.remove(&locator),
+                UndoOp::Restore(object) => storage.update(&locator, object),
+            };
+            if let Err(error) = result {
+                error!("Failed to roll back storage transaction write to {}: {}", path, error);
+            }
+        }
+    }
+}
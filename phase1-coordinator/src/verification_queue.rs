@@ -0,0 +1,221 @@
+//! A bounded producer/consumer queue that decouples accepting a
+//! contribution (`Coordinator::add_contribution`, via `try_contribute`)
+//! from verifying it, modeled on a block-import queue: producers push
+//! work and return immediately, a pool of worker threads drains it in the
+//! background, and two back-pressure limits -- a max in-memory item count
+//! and a max serialized-bytes budget -- keep an unbounded backlog of
+//! unverified contributions from piling up if verification falls behind.
+//! `push` returns a [`QueueFull`] error rather than blocking when either
+//! limit is saturated, since the coordinator's global storage lock is
+//! always held across the call.
+//!
+//! This is a separate, coordinator-internal verification path alongside
+//! the existing REST-driven `try_lock`/`run_verification`/`try_verify`
+//! flow real verifier clients use. A deployment that runs both against the
+//! same chunk lock would have the two race each other; `Coordinator`'s
+//! verification workers are meant to be the ceremony's sole verifier when
+//! this queue is enabled (see `Coordinator::start_verification_workers`).
+
+use crate::Participant;
+use std::collections::{HashSet, VecDeque};
+use std::sync::{Condvar, Mutex};
+
+/// A unique key for one queued contribution.
+pub type ContributionKey = (u64, u64, u64);
+
+/// A contribution accepted by `add_contribution` and awaiting background
+/// verification.
+#[derive(Debug, Clone)]
+pub struct QueuedContribution {
+    pub round_height: u64,
+    pub chunk_id: u64,
+    pub contribution_id: u64,
+    pub contributor: Participant,
+    /// The response file's serialized size, counted against this queue's
+    /// max-bytes back-pressure budget.
+    pub size_in_bytes: u64,
+}
+
+impl QueuedContribution {
+    fn key(&self) -> ContributionKey {
+        (self.round_height, self.chunk_id, self.contribution_id)
+    }
+}
+
+/// A snapshot of a [`VerificationQueue`]'s occupancy, for a coordinator
+/// dashboard.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct QueueInfo {
+    pub unverified_len: usize,
+    pub verifying_len: usize,
+    pub verified_len: usize,
+    pub bad_len: usize,
+    pub mem_used: u64,
+}
+
+/// Returned by [`VerificationQueue::push`] when either back-pressure limit
+/// is saturated, or the queue has been shut down.
+#[derive(Debug)]
+pub struct QueueFull;
+
+struct Inner {
+    unverified: VecDeque<QueuedContribution>,
+    verifying: HashSet<ContributionKey>,
+    verified: Vec<ContributionKey>,
+    bad: Vec<(ContributionKey, String)>,
+    mem_used: u64,
+    shutdown: bool,
+}
+
+/// A bounded queue of contributions awaiting background verification. See
+/// the module documentation for the overall design.
+pub struct VerificationQueue {
+    inner: Mutex<Inner>,
+    /// Signaled when an item is pushed, or on shutdown, so idle workers
+    /// blocked in `pop` wake up.
+    item_available: Condvar,
+    /// Signaled when the queue drains to empty, so `flush` wakes up.
+    drained: Condvar,
+    max_items: usize,
+    max_bytes: u64,
+}
+
+impl VerificationQueue {
+    /// Creates an empty queue that backs off once `max_items` contributions
+    /// are unverified-or-verifying, or their combined serialized size
+    /// reaches `max_bytes`.
+    pub fn new(max_items: usize, max_bytes: u64) -> Self {
+        Self {
+            inner: Mutex::new(Inner {
+                unverified: VecDeque::new(),
+                verifying: HashSet::new(),
+                verified: Vec::new(),
+                bad: Vec::new(),
+                mem_used: 0,
+                shutdown: false,
+            }),
+            item_available: Condvar::new(),
+            drained: Condvar::new(),
+            max_items,
+            max_bytes,
+        }
+    }
+
+    fn is_saturated(inner: &Inner, max_items: usize, max_bytes: u64) -> bool {
+        inner.unverified.len() + inner.verifying.len() >= max_items || inner.mem_used >= max_bytes
+    }
+
+    /// Pushes `item` onto the queue and wakes a worker, returning
+    /// [`QueueFull`] immediately rather than blocking if either
+    /// back-pressure limit is currently saturated or the queue has been
+    /// shut down.
+    ///
+    /// This deliberately does not block: `add_contribution`'s caller holds
+    /// the coordinator's global storage lock while pushing, and a verifier
+    /// worker needs that same lock to drain the queue and make room --
+    /// blocking here would deadlock against the very workers that would
+    /// free space.
+    pub fn push(&self, item: QueuedContribution) -> Result<(), QueueFull> {
+        let mut inner = self.inner.lock().unwrap();
+        if inner.shutdown || Self::is_saturated(&inner, self.max_items, self.max_bytes) {
+            return Err(QueueFull);
+        }
+
+        inner.mem_used += item.size_in_bytes;
+        inner.unverified.push_back(item);
+        self.item_available.notify_one();
+        Ok(())
+    }
+
+    /// Pops the next item for a worker to verify, marking it as in-flight.
+    /// Blocks until an item is available or the queue is shut down, in
+    /// which case it returns `None` so the worker loop can exit.
+    pub fn pop(&self) -> Option<QueuedContribution> {
+        let mut inner = self.inner.lock().unwrap();
+        loop {
+            if let Some(item) = inner.unverified.pop_front() {
+                inner.verifying.insert(item.key());
+                return Some(item);
+            }
+            if inner.shutdown {
+                return None;
+            }
+            inner = self.item_available.wait(inner).unwrap();
+        }
+    }
+
+    /// Records that `key` verified successfully and frees its share of the
+    /// memory budget.
+    pub fn mark_verified(&self, key: ContributionKey, size_in_bytes: u64) {
+        let mut inner = self.inner.lock().unwrap();
+        inner.verifying.remove(&key);
+        inner.mem_used = inner.mem_used.saturating_sub(size_in_bytes);
+        inner.verified.push(key);
+        self.on_item_resolved(&inner);
+    }
+
+    /// Records that `key` failed verification, with `reason` for the
+    /// dashboard, and frees its share of the memory budget.
+    pub fn mark_bad(&self, key: ContributionKey, size_in_bytes: u64, reason: String) {
+        let mut inner = self.inner.lock().unwrap();
+        inner.verifying.remove(&key);
+        inner.mem_used = inner.mem_used.saturating_sub(size_in_bytes);
+        inner.bad.push((key, reason));
+        self.on_item_resolved(&inner);
+    }
+
+    fn on_item_resolved(&self, inner: &Inner) {
+        if inner.unverified.is_empty() && inner.verifying.is_empty() {
+            self.drained.notify_all();
+        }
+    }
+
+    /// A snapshot of this queue's current occupancy.
+    pub fn info(&self) -> QueueInfo {
+        let inner = self.inner.lock().unwrap();
+        QueueInfo {
+            unverified_len: inner.unverified.len(),
+            verifying_len: inner.verifying.len(),
+            verified_len: inner.verified.len(),
+            bad_len: inner.bad.len(),
+            mem_used: inner.mem_used,
+        }
+    }
+
+    /// Returns `true` once both `unverified` and `verifying` are empty, so a
+    /// caller (e.g. `aggregate_contributions`) can check that every
+    /// contribution accepted so far has been verified or marked bad before
+    /// proceeding.
+    ///
+    /// This is a poll, not a blocking wait: `aggregate_contributions` is
+    /// called with the coordinator's global storage lock already held, and
+    /// the workers that drain this queue need that same lock to make
+    /// progress -- blocking here would deadlock against them. Use
+    /// [`VerificationQueue::wait_until_drained`] from a context that does
+    /// not hold the storage lock instead.
+    pub fn is_drained(&self) -> bool {
+        let inner = self.inner.lock().unwrap();
+        inner.unverified.is_empty() && inner.verifying.is_empty()
+    }
+
+    /// Blocks the calling thread until both `unverified` and `verifying`
+    /// are empty. Must only be called without the coordinator's storage
+    /// lock held -- see [`VerificationQueue::is_drained`].
+    pub fn wait_until_drained(&self) {
+        let inner = self.inner.lock().unwrap();
+        let _guard = self
+            .drained
+            .wait_while(inner, |inner| !inner.unverified.is_empty() || !inner.verifying.is_empty())
+            .unwrap();
+    }
+
+    /// Wakes every thread blocked in `pop` so they can observe shutdown and
+    /// exit, rather than leaving worker threads parked forever; later `push`
+    /// calls return [`QueueFull`].
+    pub fn shutdown(&self) {
+        let mut inner = self.inner.lock().unwrap();
+        inner.shutdown = true;
+        self.item_available.notify_all();
+        self.drained.notify_all();
+    }
+}
@@ -0,0 +1,100 @@
+//! Loads the verifier's configuration from `config.toml` (and, if present,
+//! `/etc/phase1-verifier/config.toml`), merged with environment variable and
+//! CLI flag overrides. This keeps the coordinator API URL, view key, and TLS
+//! material out of source -- previously hard-coded directly in `main.rs`.
+
+use figment::{
+    providers::{Env, Format, Serialized, Toml},
+    Figment,
+};
+use serde::{Deserialize, Serialize};
+
+/// Default location of the verifier config file, relative to the working directory.
+pub const DEFAULT_CONFIG_PATH: &str = "config.toml";
+/// System-wide config location. It is merged before the local config file,
+/// so settings in the local file override it.
+pub const SYSTEM_CONFIG_PATH: &str = "/etc/phase1-verifier/config.toml";
+
+/// Operator-tunable verifier configuration.
+///
+/// Everything here is safe to merge from TOML, `VERIFIER_`-prefixed
+/// environment variables, and CLI flags, in increasing priority order.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub struct VerifierConfig {
+    /// The coordinator's REST API base URL this verifier's `Verifier` client
+    /// talks to.
+    pub coordinator_api_url: String,
+    /// Path to the file holding this verifier's view key, read once at
+    /// startup rather than embedded in config directly.
+    pub view_key_path: String,
+    /// Address the WebSocket server binds to.
+    pub bind_address: String,
+    /// Path to the server's TLS certificate, enabling `wss://` when set
+    /// alongside `tls_key_path`.
+    pub tls_cert_path: Option<String>,
+    /// Path to the server's TLS private key.
+    pub tls_key_path: Option<String>,
+    /// Path to a client CA certificate. When set, the server requires every
+    /// connecting client to present a certificate signed by it (mutual TLS).
+    pub tls_client_ca_path: Option<String>,
+    /// Log level passed to `init_logger` (e.g. `"TRACE"`, `"INFO"`).
+    pub log_level: String,
+    /// Path to the SQLite job store recording lock/verify requests, so
+    /// in-flight work survives a crash. See `dbctx.rs`.
+    pub job_store_path: String,
+    /// How often, in seconds, to ping an idle WebSocket connection to check
+    /// it's still alive.
+    pub ping_interval_secs: u64,
+    /// How long, in seconds, a connection may go without a ping response (or
+    /// any other activity) before it's considered dead and closed.
+    pub idle_timeout_secs: u64,
+}
+
+const DEFAULT_COORDINATOR_API_URL: &str = "http://localhost:8000/api/coordinator";
+const DEFAULT_VIEW_KEY_PATH: &str = "view_key.txt";
+const DEFAULT_BIND_ADDRESS: &str = "0.0.0.0:8080";
+const DEFAULT_LOG_LEVEL: &str = "TRACE";
+const DEFAULT_JOB_STORE_PATH: &str = "verifier_jobs.db";
+const DEFAULT_PING_INTERVAL_SECS: u64 = 30;
+const DEFAULT_IDLE_TIMEOUT_SECS: u64 = 90;
+
+impl Default for VerifierConfig {
+    fn default() -> Self {
+        Self {
+            coordinator_api_url: DEFAULT_COORDINATOR_API_URL.to_string(),
+            view_key_path: DEFAULT_VIEW_KEY_PATH.to_string(),
+            bind_address: DEFAULT_BIND_ADDRESS.to_string(),
+            tls_cert_path: None,
+            tls_key_path: None,
+            tls_client_ca_path: None,
+            log_level: DEFAULT_LOG_LEVEL.to_string(),
+            job_store_path: DEFAULT_JOB_STORE_PATH.to_string(),
+            ping_interval_secs: DEFAULT_PING_INTERVAL_SECS,
+            idle_timeout_secs: DEFAULT_IDLE_TIMEOUT_SECS,
+        }
+    }
+}
+
+impl VerifierConfig {
+    ///
+    /// Loads the verifier configuration by merging, in order of increasing
+    /// priority: compiled-in defaults, the system config file, the local
+    /// config file, `VERIFIER_`-prefixed environment variables, and any CLI
+    /// overrides.
+    ///
+    pub fn load(cli_overrides: Figment) -> anyhow::Result<Self> {
+        let figment = Figment::from(Serialized::defaults(Self::default()))
+            .merge(Toml::file(SYSTEM_CONFIG_PATH))
+            .merge(Toml::file(DEFAULT_CONFIG_PATH))
+            .merge(Env::prefixed("VERIFIER_"))
+            .merge(cli_overrides);
+
+        Ok(figment.extract()?)
+    }
+
+    /// Reads the view key from `view_key_path`, trimming surrounding
+    /// whitespace (a trailing newline from the file is the common case).
+    pub fn read_view_key(&self) -> anyhow::Result<String> {
+        Ok(std::fs::read_to_string(&self.view_key_path)?.trim().to_string())
+    }
+}
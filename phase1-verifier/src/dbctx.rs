@@ -0,0 +1,189 @@
+//! A small SQLite-backed job store recording every lock/verify request this
+//! verifier accepts, so a crash mid-verification doesn't silently lose the
+//! work or leave a chunk's lock orphaned. `main` enqueues a job before
+//! spawning the task that drives it, marks it `running` once the task
+//! starts, and `done`/`failed` once it completes -- and on startup re-scans
+//! `pending_jobs` for anything still `pending`/`running` from a previous
+//! process, re-driving it against the coordinator.
+
+use anyhow::Context;
+use rusqlite::{params, Connection};
+use std::sync::Mutex;
+
+/// A lock/verify request's method, as recorded in the job store.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JobMethod {
+    Lock,
+    Verify,
+}
+
+impl JobMethod {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            JobMethod::Lock => "lock",
+            JobMethod::Verify => "verify",
+        }
+    }
+
+    fn parse(value: &str) -> Option<Self> {
+        match value {
+            "lock" => Some(JobMethod::Lock),
+            "verify" => Some(JobMethod::Verify),
+            _ => None,
+        }
+    }
+}
+
+/// A job's lifecycle state, as recorded in the job store.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum JobState {
+    Pending,
+    Running,
+    Done,
+    Failed,
+}
+
+impl JobState {
+    fn as_str(self) -> &'static str {
+        match self {
+            JobState::Pending => "pending",
+            JobState::Running => "running",
+            JobState::Done => "done",
+            JobState::Failed => "failed",
+        }
+    }
+}
+
+/// One lock/verify job recorded by the store, as returned by
+/// [`JobStore::pending_jobs`].
+#[derive(Debug, Clone)]
+pub struct Job {
+    pub id: i64,
+    pub chunk_id: u64,
+    pub method: JobMethod,
+}
+
+/// A chunk's most recently recorded job, as returned by
+/// [`JobStore::chunk_statuses`].
+#[derive(Debug, Clone)]
+pub struct ChunkStatus {
+    pub chunk_id: u64,
+    pub method: JobMethod,
+    pub state: String,
+}
+
+/// A SQLite-backed store of every lock/verify job this verifier has
+/// accepted. Wrapped in a `Mutex` since `rusqlite::Connection` isn't `Sync`,
+/// and job bookkeeping is infrequent enough that serializing access to it
+/// costs nothing worth avoiding.
+pub struct JobStore {
+    connection: Mutex<Connection>,
+}
+
+impl JobStore {
+    /// Opens (creating if necessary) the job store database at `path`,
+    /// creating its `jobs` table if this is the first run.
+    pub fn open(path: &str) -> anyhow::Result<Self> {
+        let connection = Connection::open(path).with_context(|| format!("failed to open job store at {}", path))?;
+        connection.execute_batch(
+            "CREATE TABLE IF NOT EXISTS jobs (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                chunk_id INTEGER NOT NULL,
+                method TEXT NOT NULL,
+                state TEXT NOT NULL
+            );",
+        )?;
+        Ok(Self {
+            connection: Mutex::new(connection),
+        })
+    }
+
+    /// Records a new job for `(chunk_id, method)` as `pending`, returning its
+    /// id -- called before the task that will drive it is spawned.
+    pub fn enqueue(&self, chunk_id: u64, method: JobMethod) -> anyhow::Result<i64> {
+        let connection = self.connection.lock().unwrap();
+        connection.execute(
+            "INSERT INTO jobs (chunk_id, method, state) VALUES (?1, ?2, ?3)",
+            params![chunk_id as i64, method.as_str(), JobState::Pending.as_str()],
+        )?;
+        Ok(connection.last_insert_rowid())
+    }
+
+    /// Marks `job_id` `running`, just before its task starts calling the
+    /// coordinator.
+    pub fn mark_running(&self, job_id: i64) -> anyhow::Result<()> {
+        self.set_state(job_id, JobState::Running)
+    }
+
+    /// Marks `job_id` `done` or `failed`, depending on `succeeded`.
+    pub fn mark_done(&self, job_id: i64, succeeded: bool) -> anyhow::Result<()> {
+        self.set_state(job_id, if succeeded { JobState::Done } else { JobState::Failed })
+    }
+
+    fn set_state(&self, job_id: i64, state: JobState) -> anyhow::Result<()> {
+        let connection = self.connection.lock().unwrap();
+        connection.execute("UPDATE jobs SET state = ?1 WHERE id = ?2", params![state.as_str(), job_id])?;
+        Ok(())
+    }
+
+    /// Each chunk's most recently recorded job and its state, oldest
+    /// `chunk_id` first -- the per-chunk progress view behind the
+    /// `/v1/status` endpoint.
+    pub fn chunk_statuses(&self) -> anyhow::Result<Vec<ChunkStatus>> {
+        let connection = self.connection.lock().unwrap();
+        let mut statement = connection.prepare(
+            "SELECT chunk_id, method, state FROM jobs
+             WHERE id IN (SELECT MAX(id) FROM jobs GROUP BY chunk_id)
+             ORDER BY chunk_id ASC",
+        )?;
+
+        let statuses = statement
+            .query_map([], |row| {
+                let chunk_id: i64 = row.get(0)?;
+                let method: String = row.get(1)?;
+                let state: String = row.get(2)?;
+                Ok((chunk_id as u64, method, state))
+            })?
+            .collect::<Result<Vec<(u64, String, String)>, _>>()?
+            .into_iter()
+            .filter_map(|(chunk_id, method, state)| {
+                Some(ChunkStatus {
+                    chunk_id,
+                    method: JobMethod::parse(&method)?,
+                    state,
+                })
+            })
+            .collect();
+
+        Ok(statuses)
+    }
+
+    /// Every job still `pending` or `running` -- left behind by a process
+    /// that died before reaching `done`/`failed` -- oldest first, so startup
+    /// can re-drive them against the coordinator in the order they were
+    /// originally accepted.
+    pub fn pending_jobs(&self) -> anyhow::Result<Vec<Job>> {
+        let connection = self.connection.lock().unwrap();
+        let mut statement =
+            connection.prepare("SELECT id, chunk_id, method FROM jobs WHERE state IN ('pending', 'running') ORDER BY id ASC")?;
+
+        let jobs = statement
+            .query_map([], |row| {
+                let method: String = row.get(2)?;
+                let chunk_id: i64 = row.get(1)?;
+                Ok((row.get(0)?, chunk_id as u64, method))
+            })?
+            .collect::<Result<Vec<(i64, u64, String)>, _>>()?
+            .into_iter()
+            .filter_map(|(id, chunk_id, method)| {
+                Some(Job {
+                    id,
+                    chunk_id,
+                    method: JobMethod::parse(&method)?,
+                })
+            })
+            .collect();
+
+        Ok(jobs)
+    }
+}
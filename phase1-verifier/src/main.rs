@@ -1,72 +1,453 @@
+mod config;
+mod dbctx;
+mod metrics;
+mod tls;
+
+use config::VerifierConfig;
+use dbctx::{JobMethod, JobStore};
+use metrics::VerifierMetrics;
+use tls::TlsConfig;
+
 use phase1_verifier::{
     logger::init_logger,
     verifier::{Verifier, VerifierRequest},
 };
 
-use futures_util::StreamExt;
-use tokio::task;
-use tracing::debug;
-use warp::{ws::WebSocket, Filter, Rejection, Reply};
-
-async fn ws_client_connection(ws: WebSocket, id: String) {
-    let (_client_ws_sender, mut client_ws_rcv) = ws.split();
-
-    // TODO (raychu86) update these hard-coded values
-    let coordinator_api_url = "http://localhost:8000/api/coordinator";
-    let view_key = "AViewKey1cWNDyYMjc9p78PnCderRx37b9pJr4myQqmmPeCfeiLf3";
-
-    println!("dsjflsdjfldsjflsdjf");
-    let verifier = Verifier::new(coordinator_api_url.to_string(), view_key.to_string()).unwrap();
-
-    while let Some(result) = client_ws_rcv.next().await {
-        match result {
-            Ok(msg) => {
-                println!("received message: {:?}", msg);
-
-                if let Ok(message_string) = msg.to_str() {
-                    // Check if the message can be deserialized into a verifier request
-                    if let Ok(verifier_request) = serde_json::from_str::<VerifierRequest>(&message_string) {
-                        if verifier_request.method.to_lowercase() == "lock" {
-                            // Spawn a task to lock the chunk
-                            let verifier_clone = verifier.clone();
-                            task::spawn(async move {
-                                if let Err(err) = verifier_clone.lock_chunk(verifier_request.chunk_id).await {
-                                    debug!("Failed to lock chunk (error {})", err);
-                                }
-                            });
-                        } else if verifier_request.method.to_lowercase() == "verify" {
-                            // Spawn a task to verify a contribution in the chunk
-                            let verifier_clone = verifier.clone();
-                            task::spawn(async move {
-                                if let Err(err) = verifier_clone.verify_contribution(verifier_request.chunk_id).await {
-                                    debug!("Failed to verify chunk (error {})", err);
+use clap::Parser;
+use figment::{providers::Serialized, Figment};
+use futures_util::{stream::SplitSink, SinkExt, StreamExt};
+use serde::Serialize;
+use std::{
+    collections::HashSet,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    time::{Duration, Instant},
+};
+use tokio::{sync::Mutex, task, time};
+use tracing::{debug, info};
+use warp::{
+    http::StatusCode,
+    ws::{Message, WebSocket},
+    Filter, Rejection, Reply,
+};
+
+/// Chunks currently locked by this verifier but not yet verified, tracked so
+/// a graceful shutdown can hand them back to the coordinator instead of
+/// leaving them locked until their lease expires on its own.
+type HeldLocks = Arc<Mutex<HashSet<u64>>>;
+
+/// Count of `run_job` tasks currently in flight, so a graceful shutdown can
+/// wait for them to finish before releasing held locks and exiting.
+type InFlightJobs = Arc<AtomicUsize>;
+
+/// CLI overrides for the verifier config, highest priority in the merge chain.
+#[derive(Parser, Debug, Default, serde::Serialize)]
+#[clap(name = "phase1-verifier")]
+struct Opt {
+    /// Override the coordinator API URL.
+    #[clap(long)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    coordinator_api_url: Option<String>,
+}
+
+/// Sent back over the socket once a spawned `lock`/`verify` task completes,
+/// so the requester gets a real response rather than a fire-and-forget
+/// `debug!` log on the verifier's side.
+#[derive(Debug, Serialize)]
+struct VerifierResponse {
+    chunk_id: u64,
+    method: String,
+    status: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+impl VerifierResponse {
+    fn ok(chunk_id: u64, method: &str) -> Self {
+        Self {
+            chunk_id,
+            method: method.to_string(),
+            status: "ok",
+            error: None,
+        }
+    }
+
+    fn error(chunk_id: u64, method: &str, error: impl ToString) -> Self {
+        Self {
+            chunk_id,
+            method: method.to_string(),
+            status: "error",
+            error: Some(error.to_string()),
+        }
+    }
+}
+
+/// One chunk's most recently recorded job, as reported by `/v1/status`.
+#[derive(Debug, Serialize)]
+struct ChunkProgress {
+    chunk_id: u64,
+    method: &'static str,
+    state: String,
+}
+
+/// Served at `/v1/status`: the current verifier state as per-chunk progress,
+/// derived from the job store.
+#[derive(Debug, Serialize)]
+struct StatusResponse {
+    chunks: Vec<ChunkProgress>,
+}
+
+/// Sends `response` back to the requester, logging (rather than propagating)
+/// any failure to do so -- the socket may simply have closed in the
+/// meantime, which isn't itself an error in the lock/verify outcome being
+/// reported.
+async fn send_response(sender: &Arc<Mutex<SplitSink<WebSocket, Message>>>, response: VerifierResponse) {
+    let message = match serde_json::to_string(&response) {
+        Ok(json) => Message::text(json),
+        Err(err) => {
+            debug!("Failed to serialize verifier response (error {})", err);
+            return;
+        }
+    };
+
+    if let Err(err) = sender.lock().await.send(message).await {
+        debug!("Failed to send verifier response (error {})", err);
+    }
+}
+
+/// Runs one lock/verify job against the coordinator: marks it `running` in
+/// `jobs`, executes it, records `done`/`failed`, and -- if a `sender` is
+/// given -- reports the outcome back over the socket. `sender` is `None`
+/// when this is a job being re-driven at startup, whose original connection
+/// is long gone.
+///
+/// Bumps `inflight` for the duration of the job so a graceful shutdown can
+/// wait for it to settle, and keeps `held_locks` in sync: a successful
+/// `lock` adds `chunk_id`, and a `verify` -- whether it succeeds or fails --
+/// removes it, since the chunk is no longer held by this verifier either way.
+async fn run_job(
+    verifier: Arc<Verifier>,
+    jobs: Arc<JobStore>,
+    metrics: Arc<VerifierMetrics>,
+    held_locks: HeldLocks,
+    inflight: InFlightJobs,
+    job_id: i64,
+    chunk_id: u64,
+    method: JobMethod,
+    sender: Option<Arc<Mutex<SplitSink<WebSocket, Message>>>>,
+) {
+    inflight.fetch_add(1, Ordering::SeqCst);
+
+    if let Err(err) = jobs.mark_running(job_id) {
+        debug!("Failed to mark job {} running (error {})", job_id, err);
+    }
+
+    let started_at = Instant::now();
+    let (method_name, result) = match method {
+        JobMethod::Lock => ("lock", verifier.lock_chunk(chunk_id).await),
+        JobMethod::Verify => ("verify", verifier.verify_contribution(chunk_id).await),
+    };
+
+    match (method, result.is_ok()) {
+        (JobMethod::Lock, true) => {
+            metrics.locks_acquired_total.inc();
+            held_locks.lock().await.insert(chunk_id);
+        }
+        (JobMethod::Lock, false) => metrics.lock_failures_total.inc(),
+        (JobMethod::Verify, true) => {
+            metrics.contributions_verified_total.inc();
+            metrics
+                .verification_duration_seconds
+                .observe(started_at.elapsed().as_secs_f64());
+            held_locks.lock().await.remove(&chunk_id);
+        }
+        (JobMethod::Verify, false) => {
+            metrics.verification_failures_total.inc();
+            held_locks.lock().await.remove(&chunk_id);
+        }
+    }
+
+    if let Err(err) = jobs.mark_done(job_id, result.is_ok()) {
+        debug!("Failed to mark job {} done (error {})", job_id, err);
+    }
+
+    let response = match result {
+        Ok(()) => VerifierResponse::ok(chunk_id, method_name),
+        Err(err) => {
+            debug!("Failed to {} chunk (error {})", method_name, err);
+            VerifierResponse::error(chunk_id, method_name, err)
+        }
+    };
+
+    if let Some(sender) = sender {
+        send_response(&sender, response).await;
+    }
+
+    inflight.fetch_sub(1, Ordering::SeqCst);
+}
+
+/// Drives one WebSocket connection: dispatches incoming lock/verify
+/// requests, and in parallel pings the client every `ping_interval` to
+/// detect a dead socket -- if nothing is heard back (a pong, or any other
+/// message) within `idle_timeout`, the connection is closed rather than left
+/// to linger forever.
+async fn ws_client_connection(
+    ws: WebSocket,
+    id: String,
+    verifier: Arc<Verifier>,
+    jobs: Arc<JobStore>,
+    metrics: Arc<VerifierMetrics>,
+    held_locks: HeldLocks,
+    inflight: InFlightJobs,
+    ping_interval: Duration,
+    idle_timeout: Duration,
+) {
+    let (client_ws_sender, mut client_ws_rcv) = ws.split();
+    // Shared behind a `Mutex` so the tasks spawned below -- each handling one
+    // request concurrently -- can each write their response without racing
+    // on the single underlying sink.
+    let client_ws_sender = Arc::new(Mutex::new(client_ws_sender));
+
+    let mut ping_ticker = time::interval(ping_interval);
+    ping_ticker.tick().await; // first tick fires immediately; consume it so pinging starts a full interval in.
+    let mut last_activity = Instant::now();
+
+    loop {
+        tokio::select! {
+            result = client_ws_rcv.next() => {
+                let Some(result) = result else { break };
+                last_activity = Instant::now();
+
+                match result {
+                    Ok(msg) => {
+                        debug!("received message: {:?}", msg);
+
+                        if let Ok(message_string) = msg.to_str() {
+                            // Check if the message can be deserialized into a verifier request
+                            if let Ok(verifier_request) = serde_json::from_str::<VerifierRequest>(&message_string) {
+                                let method = match verifier_request.method.to_lowercase().as_str() {
+                                    "lock" => Some(JobMethod::Lock),
+                                    "verify" => Some(JobMethod::Verify),
+                                    _ => None,
+                                };
+
+                                if let Some(method) = method {
+                                    // Recorded before the task runs, so a crash
+                                    // between here and completion leaves a `pending`/
+                                    // `running` row `main` will re-drive on restart.
+                                    match jobs.enqueue(verifier_request.chunk_id, method) {
+                                        Ok(job_id) => {
+                                            let verifier_clone = verifier.clone();
+                                            let jobs_clone = jobs.clone();
+                                            let metrics_clone = metrics.clone();
+                                            let held_locks_clone = held_locks.clone();
+                                            let inflight_clone = inflight.clone();
+                                            let sender = client_ws_sender.clone();
+                                            task::spawn(run_job(
+                                                verifier_clone,
+                                                jobs_clone,
+                                                metrics_clone,
+                                                held_locks_clone,
+                                                inflight_clone,
+                                                job_id,
+                                                verifier_request.chunk_id,
+                                                method,
+                                                Some(sender),
+                                            ));
+                                        }
+                                        Err(err) => debug!("Failed to enqueue job (error {})", err),
+                                    }
                                 }
-                            });
+                            }
                         }
                     }
-                }
+                    Err(e) => {
+                        debug!("error receiving ws message for id: {}): {}", id.clone(), e);
+                        break;
+                    }
+                };
             }
-            Err(e) => {
-                eprintln!("error receiving ws message for id: {}): {}", id.clone(), e);
-                break;
+            _ = ping_ticker.tick() => {
+                if last_activity.elapsed() >= idle_timeout {
+                    debug!("closing idle connection {} (no activity for {:?})", id, last_activity.elapsed());
+                    let _ = client_ws_sender.lock().await.close().await;
+                    break;
+                }
+
+                if let Err(err) = client_ws_sender.lock().await.send(Message::ping(Vec::new())).await {
+                    debug!("failed to ping connection {} (error {})", id, err);
+                    break;
+                }
             }
-        };
+        }
     }
 }
 
-pub async fn ws_handler(ws: warp::ws::Ws, id: String) -> Result<impl Reply, Rejection> {
-    Ok(ws.on_upgrade(move |socket| ws_client_connection(socket, id)))
+pub async fn ws_handler(
+    ws: warp::ws::Ws,
+    id: String,
+    verifier: Arc<Verifier>,
+    jobs: Arc<JobStore>,
+    metrics: Arc<VerifierMetrics>,
+    held_locks: HeldLocks,
+    inflight: InFlightJobs,
+    ping_interval: Duration,
+    idle_timeout: Duration,
+) -> Result<impl Reply, Rejection> {
+    Ok(ws.on_upgrade(move |socket| {
+        ws_client_connection(
+            socket,
+            id,
+            verifier,
+            jobs,
+            metrics,
+            held_locks,
+            inflight,
+            ping_interval,
+            idle_timeout,
+        )
+    }))
 }
 
 #[tokio::main]
-async fn main() {
-    init_logger("TRACE");
+async fn main() -> anyhow::Result<()> {
+    let opt = Opt::parse();
+    let config = VerifierConfig::load(Figment::from(Serialized::defaults(&opt)))?;
+
+    init_logger(&config.log_level);
+
+    // Built once at startup and shared across every connection via the warp
+    // filter below, rather than constructed fresh per socket.
+    let view_key = config.read_view_key()?;
+    let verifier = Arc::new(Verifier::new(config.coordinator_api_url.clone(), view_key)?);
+    let jobs = Arc::new(JobStore::open(&config.job_store_path)?);
+    let metrics = VerifierMetrics::new();
+    let held_locks: HeldLocks = Arc::new(Mutex::new(HashSet::new()));
+    let inflight: InFlightJobs = Arc::new(AtomicUsize::new(0));
+    let ping_interval = Duration::from_secs(config.ping_interval_secs);
+    let idle_timeout = Duration::from_secs(config.idle_timeout_secs);
+
+    // Re-drive every job a previous process accepted but never finished --
+    // left in `pending` (enqueued, never started) or `running` (started,
+    // never completed) -- before this one starts serving new connections.
+    for job in jobs.pending_jobs()? {
+        task::spawn(run_job(
+            verifier.clone(),
+            jobs.clone(),
+            metrics.clone(),
+            held_locks.clone(),
+            inflight.clone(),
+            job.id,
+            job.chunk_id,
+            job.method,
+            None,
+        ));
+    }
+
+    // Kept alongside the filters below (which each take their own clone) so
+    // shutdown can drain `inflight` and release anything left in
+    // `held_locks` once the server stops accepting connections.
+    let shutdown_verifier = verifier.clone();
+    let shutdown_held_locks = held_locks.clone();
+    let shutdown_inflight = inflight.clone();
+
+    let verifier_filter = warp::any().map(move || verifier.clone());
+    let jobs_filter = warp::any().map(move || jobs.clone());
+    let metrics_filter = warp::any().map(move || metrics.clone());
+    let held_locks_filter = warp::any().map(move || held_locks.clone());
+    let inflight_filter = warp::any().map(move || inflight.clone());
+    let ping_interval_filter = warp::any().map(move || ping_interval);
+    let idle_timeout_filter = warp::any().map(move || idle_timeout);
 
     let ws_route = warp::path("ws")
         .and(warp::ws())
         .and(warp::path::param())
+        .and(verifier_filter)
+        .and(jobs_filter.clone())
+        .and(metrics_filter.clone())
+        .and(held_locks_filter)
+        .and(inflight_filter)
+        .and(ping_interval_filter)
+        .and(idle_timeout_filter)
         .and_then(ws_handler);
 
-    println!("Started on port 8080");
-    warp::serve(ws_route).run(([0, 0, 0, 0], 8080)).await;
+    let health_route = warp::path!("v1" / "health").map(|| warp::reply::with_status("ok", StatusCode::OK));
+
+    let status_route = warp::path!("v1" / "status").and(jobs_filter).map(|jobs: Arc<JobStore>| {
+        let chunks = jobs
+            .chunk_statuses()
+            .unwrap_or_default()
+            .into_iter()
+            .map(|status| ChunkProgress {
+                chunk_id: status.chunk_id,
+                method: status.method.as_str(),
+                state: status.state,
+            })
+            .collect();
+        warp::reply::json(&StatusResponse { chunks })
+    });
+
+    let metrics_route = warp::path("metrics")
+        .and(metrics_filter)
+        .map(|metrics: Arc<VerifierMetrics>| metrics.encode());
+
+    let routes = health_route.or(status_route).or(metrics_route).or(ws_route);
+
+    let tls_config = TlsConfig::load(
+        config.tls_cert_path.as_deref().map(std::path::Path::new),
+        config.tls_key_path.as_deref().map(std::path::Path::new),
+        config.tls_client_ca_path.as_deref().map(std::path::Path::new),
+    )?;
+
+    let bind_address: std::net::SocketAddr = config.bind_address.parse()?;
+
+    let shutdown_signal = async {
+        let _ = tokio::signal::ctrl_c().await;
+        info!("Shutdown signal received, no longer accepting new connections");
+    };
+
+    if tls_config.is_enabled() {
+        let mut server = warp::serve(routes)
+            .tls()
+            .cert(tls_config.tls_cert.expect("checked by is_enabled"))
+            .key(tls_config.tls_key.expect("checked by is_enabled"));
+
+        if let Some(root_certificate) = tls_config.root_certificate {
+            // Require every client to present a certificate signed by this
+            // CA, rather than merely accept one if offered -- only an
+            // authorized verifier should ever reach `ws_handler`.
+            server = server.client_auth_required(root_certificate);
+        }
+
+        info!("Started on {} (wss)", bind_address);
+        let (_, server) = server.bind_with_graceful_shutdown(bind_address, shutdown_signal);
+        server.await;
+    } else {
+        info!("Started on {}", bind_address);
+        let (_, server) = warp::serve(routes).bind_with_graceful_shutdown(bind_address, shutdown_signal);
+        server.await;
+    }
+
+    drain_and_release(shutdown_inflight, shutdown_held_locks, shutdown_verifier).await;
+
+    Ok(())
+}
+
+/// Waits for every in-flight `run_job` task to finish, then releases any
+/// chunk lock still recorded in `held_locks` back to the coordinator --
+/// called after the server has stopped accepting new connections, so a
+/// shutdown doesn't abandon a lock a connected participant is still waiting
+/// on to expire naturally.
+async fn drain_and_release(inflight: InFlightJobs, held_locks: HeldLocks, verifier: Arc<Verifier>) {
+    while inflight.load(Ordering::SeqCst) > 0 {
+        time::sleep(Duration::from_millis(100)).await;
+    }
+
+    for chunk_id in held_locks.lock().await.drain() {
+        if let Err(err) = verifier.unlock_chunk(chunk_id).await {
+            debug!("Failed to release lock on chunk {} during shutdown (error {})", chunk_id, err);
+        }
+    }
 }
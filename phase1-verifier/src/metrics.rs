@@ -0,0 +1,72 @@
+//! A Prometheus metrics registry for a running verifier.
+//!
+//! Mirrors `phase1-coordinator`'s `CeremonyMetrics`: one registry, created
+//! once at startup and updated at the points where locks and verifications
+//! occur, so operators can scrape throughput and spot a stalled verifier
+//! without reading `TRACE` logs.
+
+use prometheus::{Encoder, Histogram, HistogramOpts, IntCounter, Registry, TextEncoder};
+use std::sync::Arc;
+
+/// Verifier-wide Prometheus metrics, cheaply cloneable and safe to share
+/// across every connection and the `/metrics` endpoint.
+#[derive(Clone)]
+pub struct VerifierMetrics {
+    registry: Registry,
+    pub locks_acquired_total: IntCounter,
+    pub contributions_verified_total: IntCounter,
+    pub verification_duration_seconds: Histogram,
+    pub lock_failures_total: IntCounter,
+    pub verification_failures_total: IntCounter,
+}
+
+impl VerifierMetrics {
+    /// Creates a new metrics registry with all verifier counters/histograms registered.
+    pub fn new() -> Arc<Self> {
+        let registry = Registry::new();
+
+        let locks_acquired_total =
+            IntCounter::new("verifier_locks_acquired_total", "Total chunk locks successfully acquired").unwrap();
+        let contributions_verified_total = IntCounter::new(
+            "verifier_contributions_verified_total",
+            "Total contributions successfully verified",
+        )
+        .unwrap();
+        let verification_duration_seconds = Histogram::with_opts(HistogramOpts::new(
+            "verifier_verification_duration_seconds",
+            "Time spent running a single contribution verification, in seconds",
+        ))
+        .unwrap();
+        let lock_failures_total = IntCounter::new("verifier_lock_failures_total", "Total failed lock attempts").unwrap();
+        let verification_failures_total =
+            IntCounter::new("verifier_verification_failures_total", "Total failed verification attempts").unwrap();
+
+        for metric in [
+            Box::new(locks_acquired_total.clone()) as Box<dyn prometheus::core::Collector>,
+            Box::new(contributions_verified_total.clone()),
+            Box::new(verification_duration_seconds.clone()),
+            Box::new(lock_failures_total.clone()),
+            Box::new(verification_failures_total.clone()),
+        ] {
+            registry.register(metric).expect("Metric names must not collide");
+        }
+
+        Arc::new(Self {
+            registry,
+            locks_acquired_total,
+            contributions_verified_total,
+            verification_duration_seconds,
+            lock_failures_total,
+            verification_failures_total,
+        })
+    }
+
+    /// Renders all registered metrics in Prometheus text exposition format.
+    pub fn encode(&self) -> String {
+        let mut buffer = Vec::new();
+        TextEncoder::new()
+            .encode(&self.registry.gather(), &mut buffer)
+            .expect("Metric families must be encodable");
+        String::from_utf8(buffer).expect("Prometheus text output is always valid UTF-8")
+    }
+}
@@ -0,0 +1,43 @@
+//! TLS/mTLS configuration for the verifier's WebSocket server, so
+//! verifier<->coordinator traffic isn't sent over a plaintext `ws://`
+//! connection.
+//!
+//! Each of `tls_cert`, `tls_key`, and `root_certificate` is an optional path,
+//! read once into a byte cache at startup rather than re-read per connection.
+//! `root_certificate`, when set, enables mutual TLS: the server is configured
+//! to reject any upgrade from a client that doesn't present a certificate
+//! signed by it, so only authorized verifiers can connect.
+
+use std::{fs, io, path::Path};
+
+/// Certificates and keys for the verifier's WebSocket server, cached in
+/// memory once at startup. `None` fields mean TLS is unconfigured and the
+/// server falls back to plain `ws://`.
+#[derive(Debug, Clone, Default)]
+pub struct TlsConfig {
+    pub tls_cert: Option<Vec<u8>>,
+    pub tls_key: Option<Vec<u8>>,
+    pub root_certificate: Option<Vec<u8>>,
+}
+
+impl TlsConfig {
+    /// Reads `tls_cert`, `tls_key`, and `root_certificate` from the given
+    /// optional paths into memory, if present. A path that's `Some` but
+    /// doesn't exist on disk is an error -- an operator who asked for TLS
+    /// should find out immediately that it's misconfigured, not have the
+    /// server silently fall back to plaintext.
+    pub fn load(tls_cert: Option<&Path>, tls_key: Option<&Path>, root_certificate: Option<&Path>) -> io::Result<Self> {
+        Ok(Self {
+            tls_cert: tls_cert.map(fs::read).transpose()?,
+            tls_key: tls_key.map(fs::read).transpose()?,
+            root_certificate: root_certificate.map(fs::read).transpose()?,
+        })
+    }
+
+    /// Whether a server certificate and key have been configured -- the
+    /// minimum needed to serve `wss://` at all. Mutual TLS via
+    /// `root_certificate` is optional on top of this.
+    pub fn is_enabled(&self) -> bool {
+        self.tls_cert.is_some() && self.tls_key.is_some()
+    }
+}
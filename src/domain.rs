@@ -16,8 +16,9 @@ use pairing::{
 };
 
 use ff::{
-    Field, 
-    PrimeField
+    Field,
+    PrimeField,
+    PrimeFieldRepr
 };
 
 use super::{
@@ -31,13 +32,71 @@ pub use super::group::*;
 #[cfg(not(feature = "singlecore"))]
 use super::parallel_fft::*;
 
+/// The extra domain parameters needed to evaluate a quotient polynomial on a
+/// coset large enough to hold it, computed once by
+/// `from_coeffs_with_quotient_degree` and reused by every
+/// `extended_coset_fft`/`extended_icoset_fft`/`divide_by_z_on_extended_coset`
+/// call over the life of the domain.
+struct ExtendedDomain<E: Engine> {
+    exp: u32,
+    omega: E::Fr,
+    omega_inv: E::Fr,
+    minv: E::Fr,
+    /// Inverses of the vanishing polynomial `t(X) = X^m - 1` of the
+    /// *un*-extended domain, evaluated at the extended coset's points.
+    /// These repeat with period `t_evaluations.len() == extended_m / m`
+    /// (the extended size over this domain's size, at least `j - 1`), so
+    /// only that many are stored.
+    t_evaluations: Vec<E::Fr>,
+}
+
+/// Montgomery batch inversion: inverts every element of `self` in place
+/// using a single field inversion, rather than one inversion per element.
+/// Zero entries are left as zero (rather than panicking), since a point
+/// that happens to land exactly on a root of the set being divided out is
+/// a legitimate, if unlucky, input.
+pub trait BatchInvert<F> {
+    fn batch_invert(self);
+}
+
+impl<'a, F: Field> BatchInvert<F> for &'a mut [F] {
+    fn batch_invert(self) {
+        let mut products = Vec::with_capacity(self.len());
+        let mut acc = F::one();
+        for value in self.iter() {
+            if !value.is_zero() {
+                products.push(acc);
+                acc.mul_assign(value);
+            } else {
+                products.push(F::zero());
+            }
+        }
+
+        // `acc` is a product of only the non-zero entries seen so far, so
+        // it's always invertible even when every entry turns out to be zero.
+        let mut acc_inv = acc.inverse().unwrap();
+
+        for (value, product) in self.iter_mut().zip(products.into_iter()).rev() {
+            if value.is_zero() {
+                continue;
+            }
+
+            let mut inv = acc_inv;
+            inv.mul_assign(&product);
+            acc_inv.mul_assign(value);
+            *value = inv;
+        }
+    }
+}
+
 pub struct EvaluationDomain<E: Engine, G: Group<E>> {
     coeffs: Vec<G>,
     exp: u32,
     omega: E::Fr,
     omegainv: E::Fr,
     geninv: E::Fr,
-    minv: E::Fr
+    minv: E::Fr,
+    extended: Option<ExtendedDomain<E>>,
 }
 
 impl<E: Engine, G: Group<E>> EvaluationDomain<E, G> {
@@ -98,7 +157,8 @@ impl<E: Engine, G: Group<E>> EvaluationDomain<E, G> {
             omega: omega,
             omegainv: omega.inverse().unwrap(),
             geninv: E::Fr::multiplicative_generator().inverse().unwrap(),
-            minv: E::Fr::from_str(&format!("{}", m)).unwrap().inverse().unwrap()
+            minv: E::Fr::from_str(&format!("{}", m)).unwrap().inverse().unwrap(),
+            extended: None,
         })
     }
 
@@ -150,10 +210,151 @@ impl<E: Engine, G: Group<E>> EvaluationDomain<E, G> {
             omega: omega,
             omegainv: omega.inverse().unwrap(),
             geninv: E::Fr::multiplicative_generator().inverse().unwrap(),
-            minv: E::Fr::from_str(&format!("{}", m)).unwrap().inverse().unwrap()
+            minv: E::Fr::from_str(&format!("{}", m)).unwrap().inverse().unwrap(),
+            extended: None,
         })
     }
 
+    /// Like `from_coeffs`, but also precomputes the extended coset domain
+    /// needed to construct a degree-`j` quotient polynomial over `coeffs`,
+    /// so that a subsequent `extended_coset_fft`/`divide_by_z_on_extended_coset`
+    /// doesn't have to recompute it.
+    pub fn from_coeffs_with_quotient_degree(coeffs: Vec<G>, j: usize) -> Result<EvaluationDomain<E, G>, SynthesisError>
+    {
+        let mut domain = Self::from_coeffs(coeffs)?;
+        domain.extend_for_quotient_degree(j)?;
+        Ok(domain)
+    }
+
+    /// Computes and stores the `ExtendedDomain` needed to hold a degree-`j`
+    /// quotient polynomial over this domain, picking `extended_exp` as the
+    /// smallest value with `2^extended_exp >= n * (j - 1)`, where `n` is
+    /// this domain's size.
+    fn extend_for_quotient_degree(&mut self, j: usize) -> Result<(), SynthesisError>
+    {
+        use ff::PrimeField;
+
+        assert!(j >= 2, "quotient degree factor must be at least 2");
+
+        let n = self.coeffs.len();
+        let min_extended = n * (j - 1);
+        let max_degree = (1 << E::Fr::S) - 1;
+
+        if min_extended > max_degree {
+            return Err(SynthesisError::PolynomialDegreeTooLarge)
+        }
+
+        let mut extended_m = 1;
+        let mut extended_exp = 0;
+        let mut extended_omega = E::Fr::root_of_unity();
+
+        while extended_m < min_extended {
+            extended_m *= 2;
+            extended_exp += 1;
+
+            if extended_exp > E::Fr::S {
+                return Err(SynthesisError::PolynomialDegreeTooLarge)
+            }
+        }
+
+        for _ in extended_exp..E::Fr::S {
+            extended_omega.square();
+        }
+
+        let extended_omega_inv = extended_omega.inverse().unwrap();
+        let extended_minv = E::Fr::from_str(&format!("{}", extended_m)).unwrap().inverse().unwrap();
+
+        // t(X) = X^n - 1 evaluated at the extended coset's points repeats
+        // with period `extended_m / n`, since `omega_ext^n` has exactly
+        // that order; so we only need to store that many inverses.
+        let period = extended_m / n;
+        let g_to_n = E::Fr::multiplicative_generator().pow(&[n as u64]);
+        let omega_to_n = extended_omega.pow(&[n as u64]);
+
+        let mut t_evaluations = Vec::with_capacity(period);
+        let mut current = g_to_n;
+        for _ in 0..period {
+            let mut t = current;
+            t.sub_assign(&E::Fr::one());
+            t_evaluations.push(t.inverse().unwrap());
+            current.mul_assign(&omega_to_n);
+        }
+
+        self.extended = Some(ExtendedDomain {
+            exp: extended_exp,
+            omega: extended_omega,
+            omega_inv: extended_omega_inv,
+            minv: extended_minv,
+            t_evaluations,
+        });
+
+        Ok(())
+    }
+
+    /// Performs a coset FFT into the extended domain computed by
+    /// `from_coeffs_with_quotient_degree`, resizing `coeffs` up to the
+    /// extended domain's size first.
+    ///
+    /// Panics if this domain wasn't constructed with
+    /// `from_coeffs_with_quotient_degree`.
+    pub fn extended_coset_fft(&mut self, worker: &Worker)
+    {
+        let ext = self.extended.as_ref().expect("extended domain not computed; use from_coeffs_with_quotient_degree");
+        let extended_exp = ext.exp;
+        let extended_omega = ext.omega;
+
+        self.coeffs.resize(1 << extended_exp, G::group_zero());
+        self.distribute_powers(worker, E::Fr::multiplicative_generator());
+        best_fft(&mut self.coeffs, worker, &extended_omega, extended_exp);
+    }
+
+    /// The inverse of `extended_coset_fft`.
+    pub fn extended_icoset_fft(&mut self, worker: &Worker)
+    {
+        let ext = self.extended.as_ref().expect("extended domain not computed; use from_coeffs_with_quotient_degree");
+        let extended_exp = ext.exp;
+        let extended_omega_inv = ext.omega_inv;
+        let extended_minv = ext.minv;
+        let geninv = self.geninv;
+
+        best_fft(&mut self.coeffs, worker, &extended_omega_inv, extended_exp);
+
+        worker.scope(self.coeffs.len(), |scope, chunk| {
+            for v in self.coeffs.chunks_mut(chunk) {
+                scope.spawn(move |_| {
+                    for v in v {
+                        v.group_mul_assign(&extended_minv);
+                    }
+                });
+            }
+        });
+
+        self.distribute_powers(worker, geninv);
+    }
+
+    /// Divides the polynomial currently held in extended-coset evaluation
+    /// form by the vanishing polynomial of the *un*-extended domain,
+    /// `t(X) = X^n - 1`, using the precomputed `t_evaluations`.
+    pub fn divide_by_z_on_extended_coset(&mut self, worker: &Worker)
+    {
+        let t_evaluations = self.extended.as_ref()
+            .expect("extended domain not computed; use from_coeffs_with_quotient_degree")
+            .t_evaluations
+            .clone();
+        let period = t_evaluations.len();
+
+        worker.scope(self.coeffs.len(), |scope, chunk| {
+            for (chunk_index, v) in self.coeffs.chunks_mut(chunk).enumerate() {
+                let t_evaluations = &t_evaluations;
+                scope.spawn(move |_| {
+                    for (i, v) in v.iter_mut().enumerate() {
+                        let t = t_evaluations[(chunk_index * chunk + i) % period];
+                        v.group_mul_assign(&t);
+                    }
+                });
+            }
+        });
+    }
 
     pub fn fft(&mut self, worker: &Worker)
     {
@@ -233,6 +434,54 @@ impl<E: Engine, G: Group<E>> EvaluationDomain<E, G> {
         });
     }
 
+    /// Divides the polynomial currently held in coset-evaluation form (see
+    /// `coset_fft`) by the vanishing polynomial of an arbitrary set of
+    /// `roots`, rather than this domain's own `z(X) = X^m - 1`. A point
+    /// landing exactly on one of `roots` leaves the corresponding
+    /// coefficient untouched instead of panicking.
+    pub fn divide_by_vanishing_on_coset(&mut self, worker: &Worker, roots: &[E::Fr])
+    {
+        let g = E::Fr::multiplicative_generator();
+        let omega = self.omega;
+        let n = self.coeffs.len();
+
+        let mut denominators = vec![E::Fr::zero(); n];
+
+        worker.scope(n, |scope, chunk| {
+            for (chunk_index, den) in denominators.chunks_mut(chunk).enumerate() {
+                scope.spawn(move |_| {
+                    let mut x = g;
+                    x.mul_assign(&omega.pow(&[(chunk_index * chunk) as u64]));
+
+                    for d in den.iter_mut() {
+                        let mut v = E::Fr::one();
+                        for r in roots {
+                            let mut t = x;
+                            t.sub_assign(r);
+                            v.mul_assign(&t);
+                        }
+                        *d = v;
+                        x.mul_assign(&omega);
+                    }
+                });
+            }
+        });
+
+        denominators.as_mut_slice().batch_invert();
+
+        worker.scope(n, |scope, chunk| {
+            for (v, inv) in self.coeffs.chunks_mut(chunk).zip(denominators.chunks(chunk)) {
+                scope.spawn(move |_| {
+                    for (v, inv) in v.iter_mut().zip(inv.iter()) {
+                        if !inv.is_zero() {
+                            v.group_mul_assign(inv);
+                        }
+                    }
+                });
+            }
+        });
+    }
+
     /// Perform O(n) multiplication of two polynomials in the domain.
     pub fn mul_assign(&mut self, worker: &Worker, other: &EvaluationDomain<E, Scalar<E>>) {
         assert_eq!(self.coeffs.len(), other.coeffs.len());
@@ -264,6 +513,300 @@ impl<E: Engine, G: Group<E>> EvaluationDomain<E, G> {
     }
 }
 
+impl<E: Engine> EvaluationDomain<E, Scalar<E>> {
+    /// Evaluates the polynomial currently held in evaluation (post-`fft`)
+    /// form at an arbitrary `x`, without performing an inverse FFT first,
+    /// using the barycentric interpolation formula over this domain's
+    /// `n`-th roots of unity:
+    ///
+    /// f(x) = (x^n - 1)/n * sum_i [ omega^i * f_i / (x - omega^i) ]
+    ///
+    /// If `x` coincides with a domain point `omega^i`, `f_i` is returned
+    /// directly rather than dividing by zero.
+    pub fn evaluate_at(&self, x: E::Fr) -> E::Fr {
+        let n = self.coeffs.len();
+
+        let mut denominators = Vec::with_capacity(n);
+        let mut omega_pow = E::Fr::one();
+        for _ in 0..n {
+            let mut d = x;
+            d.sub_assign(&omega_pow);
+            denominators.push(d);
+            omega_pow.mul_assign(&self.omega);
+        }
+
+        if let Some(i) = denominators.iter().position(|d| d.is_zero()) {
+            return self.coeffs[i].0;
+        }
+
+        denominators.as_mut_slice().batch_invert();
+
+        let mut result = E::Fr::zero();
+        let mut omega_pow = E::Fr::one();
+        for (f_i, inv_denom) in self.coeffs.iter().zip(denominators.iter()) {
+            let mut term = f_i.0;
+            term.mul_assign(&omega_pow);
+            term.mul_assign(inv_denom);
+            result.add_assign(&term);
+            omega_pow.mul_assign(&self.omega);
+        }
+
+        let mut scale = x.pow(&[n as u64]);
+        scale.sub_assign(&E::Fr::one());
+        scale.mul_assign(&self.minv);
+        result.mul_assign(&scale);
+
+        result
+    }
+
+    /// Commits to the polynomial currently held by this domain against the
+    /// provided powers of tau, computing `sum_i coeffs[i] * powers_of_tau[i]`
+    /// with the windowed-bucket (Pippenger) multiexponentiation method.
+    pub fn commit(&self, worker: &Worker, powers_of_tau: &[E::G1Affine]) -> E::G1 {
+        assert!(powers_of_tau.len() >= self.coeffs.len());
+
+        multiexp::<E>(worker, &self.coeffs, &powers_of_tau[..self.coeffs.len()])
+    }
+}
+
+/// Windowed-bucket (Pippenger) multiexponentiation of `scalars` against
+/// `bases`, dividing the work into worker-parallel chunks and summing each
+/// chunk's contribution.
+fn multiexp<E: Engine>(worker: &Worker, scalars: &[Scalar<E>], bases: &[E::G1Affine]) -> E::G1 {
+    assert_eq!(scalars.len(), bases.len());
+
+    // A larger window pays for itself (fewer passes over the bases) only
+    // once there are enough terms to fill its buckets; past 32 terms the
+    // window is sized to roughly ln(n), the natural log of the term count.
+    let c = if scalars.len() < 32 {
+        3
+    } else {
+        ((scalars.len() as f64).ln().ceil() as usize).max(1)
+    };
+
+    let (sender, receiver) = std::sync::mpsc::channel();
+
+    worker.scope(scalars.len(), |scope, chunk_size| {
+        for (scalars_chunk, bases_chunk) in scalars.chunks(chunk_size).zip(bases.chunks(chunk_size)) {
+            let sender = sender.clone();
+            scope.spawn(move |_| {
+                sender
+                    .send(windowed_bucket_multiexp::<E>(scalars_chunk, bases_chunk, c))
+                    .expect("receiver outlives every spawned chunk");
+            });
+        }
+    });
+    drop(sender);
+
+    let mut acc = E::G1::zero();
+    for partial in receiver.iter() {
+        acc.add_assign(&partial);
+    }
+    acc
+}
+
+/// Pippenger's windowed-bucket method over a single chunk of terms: splits
+/// the scalar field's bit-width into `c`-bit windows, accumulates each
+/// window's bases into `2^c - 1` buckets keyed by the window's digit, sums
+/// each window's buckets with a single weighted running sum, then combines
+/// the windows from most to least significant with `c` doublings apiece.
+fn windowed_bucket_multiexp<E: Engine>(scalars: &[Scalar<E>], bases: &[E::G1Affine], c: usize) -> E::G1 {
+    let num_bits = E::Fr::NUM_BITS as usize;
+    let num_windows = (num_bits + c - 1) / c;
+
+    let mut result = E::G1::zero();
+
+    for window in (0..num_windows).rev() {
+        for _ in 0..c {
+            result.double();
+        }
+
+        let mut buckets = vec![E::G1::zero(); (1 << c) - 1];
+
+        for (scalar, base) in scalars.iter().zip(bases.iter()) {
+            let digit = scalar_window_digit(&scalar.0, window, c);
+            if digit != 0 {
+                buckets[digit - 1].add_assign_mixed(base);
+            }
+        }
+
+        // Summing `buckets[k]` weighted by `k + 1` in one pass: a running
+        // sum of the buckets seen so far (from the top down) is itself
+        // accumulated into the window's total, which adds each bucket in
+        // exactly as many times as its weight.
+        let mut running = E::G1::zero();
+        let mut window_sum = E::G1::zero();
+        for bucket in buckets.into_iter().rev() {
+            running.add_assign(&bucket);
+            window_sum.add_assign(&running);
+        }
+
+        result.add_assign(&window_sum);
+    }
+
+    result
+}
+
+/// Extracts the `c`-bit digit starting at bit `window * c` of `scalar`'s
+/// little-endian limb representation.
+fn scalar_window_digit<F: PrimeField>(scalar: &F, window: usize, c: usize) -> usize {
+    let repr = scalar.into_repr();
+    let limbs = repr.as_ref();
+    let bit_start = window * c;
+
+    let mut digit = 0usize;
+    for i in 0..c {
+        let bit_index = bit_start + i;
+        let limb = bit_index / 64;
+        let offset = bit_index % 64;
+        if limb < limbs.len() && (limbs[limb] >> offset) & 1 == 1 {
+            digit |= 1 << i;
+        }
+    }
+    digit
+}
+
+/// Marker for a vector of coefficients of a polynomial in the monomial basis.
+#[derive(Clone, Copy, Debug)]
+pub struct Coeff;
+
+/// Marker for a vector of evaluations of a polynomial over this domain's
+/// roots of unity (the result of `fft`).
+#[derive(Clone, Copy, Debug)]
+pub struct LagrangeCoeff;
+
+/// Marker for a vector of evaluations of a polynomial over a coset of the
+/// domain (the result of `Polynomial::coset_fft`). That coset has the same
+/// size as the domain; the extended domain of
+/// `from_coeffs_with_quotient_degree` is not used by `Polynomial`.
+#[derive(Clone, Copy, Debug)]
+pub struct ExtendedLagrangeCoeff;
+
+/// Implemented by the three basis marker types, so `Polynomial` can be
+/// generic over which one tags a given vector of values.
+pub trait Basis: Clone + Copy + std::fmt::Debug {}
+impl Basis for Coeff {}
+impl Basis for LagrangeCoeff {}
+impl Basis for ExtendedLagrangeCoeff {}
+
+/// Implemented by the basis tags that represent evaluations rather than
+/// coefficients, since pointwise multiplication only makes sense on values
+/// that are already evaluations over the same domain.
+pub trait EvaluationBasis: Basis {}
+impl EvaluationBasis for LagrangeCoeff {}
+impl EvaluationBasis for ExtendedLagrangeCoeff {}
+
+/// A thin wrapper over a vector of `G` tagged at the type level with which
+/// basis it's currently expressed in, so that `fft`/`ifft`/`coset_fft` are
+/// the only way to move between bases and operations like `mul_assign` that
+/// only make sense on evaluations can't accidentally be called on
+/// coefficients (or vice versa). Built on top of `EvaluationDomain`, which
+/// remains the untyped, in-place API used where the basis is tracked by
+/// hand instead.
+pub struct Polynomial<E: Engine, G: Group<E>, B: Basis> {
+    values: Vec<G>,
+    _basis: std::marker::PhantomData<B>,
+}
+
+impl<E: Engine, G: Group<E>, B: Basis> Polynomial<E, G, B> {
+    fn wrap(values: Vec<G>) -> Self {
+        Polynomial { values, _basis: std::marker::PhantomData }
+    }
+
+    pub fn as_ref(&self) -> &[G] {
+        &self.values
+    }
+
+    pub fn as_mut(&mut self) -> &mut [G] {
+        &mut self.values
+    }
+
+    pub fn into_values(self) -> Vec<G> {
+        self.values
+    }
+
+    /// Perform O(n) subtraction of one polynomial from another; both sides
+    /// must already be expressed in the same basis.
+    pub fn sub_assign(&mut self, worker: &Worker, other: &Polynomial<E, G, B>) {
+        assert_eq!(self.values.len(), other.values.len());
+
+        worker.scope(self.values.len(), |scope, chunk| {
+            for (a, b) in self.values.chunks_mut(chunk).zip(other.values.chunks(chunk)) {
+                scope.spawn(move |_| {
+                    for (a, b) in a.iter_mut().zip(b.iter()) {
+                        a.group_sub_assign(&b);
+                    }
+                });
+            }
+        });
+    }
+}
+
+impl<E: Engine, G: Group<E>> Polynomial<E, G, Coeff> {
+    /// Wraps coefficients that haven't yet been transformed into any
+    /// evaluation domain.
+    pub fn from_coeffs(values: Vec<G>) -> Self {
+        Self::wrap(values)
+    }
+
+    pub fn fft(self, worker: &Worker) -> Result<Polynomial<E, G, LagrangeCoeff>, SynthesisError> {
+        let mut domain = EvaluationDomain::from_coeffs(self.values)?;
+        domain.fft(worker);
+        Ok(Polynomial::wrap(domain.into_coeffs()))
+    }
+
+    pub fn coset_fft(self, worker: &Worker) -> Result<Polynomial<E, G, ExtendedLagrangeCoeff>, SynthesisError> {
+        let mut domain = EvaluationDomain::from_coeffs(self.values)?;
+        domain.coset_fft(worker);
+        Ok(Polynomial::wrap(domain.into_coeffs()))
+    }
+}
+
+impl<E: Engine, G: Group<E>> Polynomial<E, G, LagrangeCoeff> {
+    pub fn ifft(self, worker: &Worker) -> Result<Polynomial<E, G, Coeff>, SynthesisError> {
+        let mut domain = EvaluationDomain::from_coeffs(self.values)?;
+        domain.ifft(worker);
+        Ok(Polynomial::wrap(domain.into_coeffs()))
+    }
+}
+
+impl<E: Engine, G: Group<E>> Polynomial<E, G, ExtendedLagrangeCoeff> {
+    pub fn icoset_fft(self, worker: &Worker) -> Result<Polynomial<E, G, Coeff>, SynthesisError> {
+        let mut domain = EvaluationDomain::from_coeffs(self.values)?;
+        domain.icoset_fft(worker);
+        Ok(Polynomial::wrap(domain.into_coeffs()))
+    }
+
+    /// The target polynomial is zero on the domain itself, so division by
+    /// it is only meaningful on the coset evaluations.
+    pub fn divide_by_z_on_coset(&mut self, worker: &Worker) {
+        let mut domain = EvaluationDomain::from_coeffs(std::mem::take(&mut self.values))
+            .expect("size was already validated when this Polynomial was constructed");
+        domain.divide_by_z_on_coset(worker);
+        self.values = domain.into_coeffs();
+    }
+}
+
+impl<E: Engine, G: Group<E>, B: EvaluationBasis> Polynomial<E, G, B> {
+    /// Perform O(n) multiplication of two polynomials, restricted to
+    /// evaluation-basis values since that's the only basis in which
+    /// polynomial multiplication is a pointwise O(n) operation rather than
+    /// a convolution.
+    pub fn mul_assign(&mut self, worker: &Worker, other: &Polynomial<E, Scalar<E>, B>) {
+        assert_eq!(self.values.len(), other.values.len());
+
+        worker.scope(self.values.len(), |scope, chunk| {
+            for (a, b) in self.values.chunks_mut(chunk).zip(other.values.chunks(chunk)) {
+                scope.spawn(move |_| {
+                    for (a, b) in a.iter_mut().zip(b.iter()) {
+                        a.group_mul_assign(&b.0);
+                    }
+                });
+            }
+        });
+    }
+}
+
 // Test multiplying various (low degree) polynomials together and
 // comparing with naive evaluations.
 #[test]
@@ -384,6 +927,161 @@ fn parallel_fft_consistency() {
     test_consistency::<Bls12, _>(rng);
 }
 
+// Test `evaluate_at` against a naive Horner evaluation of the same
+// polynomial's monomial-form coefficients, at both arbitrary points and
+// the domain's own roots of unity (which take `evaluate_at`'s
+// zero-denominator short-circuit instead of the general formula).
+#[test]
+fn evaluate_at_consistency() {
+    use pairing::bls12_381::Bls12;
+    use rand::{self, Rand};
+
+    fn horner_eval<E: Engine>(coeffs: &[Scalar<E>], x: E::Fr) -> E::Fr {
+        let mut acc = E::Fr::zero();
+        for c in coeffs.iter().rev() {
+            acc.mul_assign(&x);
+            acc.add_assign(&c.0);
+        }
+        acc
+    }
+
+    fn test_evaluate_at<E: Engine, R: rand::Rng>(rng: &mut R)
+    {
+        let worker = Worker::new();
+
+        for log_d in 0..8 {
+            let d = 1 << log_d;
+
+            let coeffs: Vec<_> = (0..d).map(|_| Scalar::<E>(E::Fr::rand(rng))).collect();
+
+            // `from_coeffs` pads up to the next power of two with zeros;
+            // capture that padded form as the reference before `fft`
+            // overwrites `domain.coeffs` with evaluations.
+            let mut domain = EvaluationDomain::from_coeffs(coeffs).unwrap();
+            let padded_coeffs = domain.coeffs.clone();
+            domain.fft(&worker);
+
+            for _ in 0..5 {
+                let x = E::Fr::rand(rng);
+                assert!(horner_eval::<E>(&padded_coeffs, x) == domain.evaluate_at(x));
+            }
+
+            assert!(horner_eval::<E>(&padded_coeffs, domain.omega) == domain.evaluate_at(domain.omega));
+        }
+    }
+
+    let rng = &mut rand::thread_rng();
+
+    test_evaluate_at::<Bls12, _>(rng);
+}
+
+// Test `divide_by_vanishing_on_coset` by constructing `p(X) = vanishing(X)
+// * q(X)` for a random `q` via naive convolution, then checking that
+// dividing `p` by `vanishing`'s roots on a coset recovers `q` exactly.
+#[test]
+fn divide_by_vanishing_on_coset_consistency() {
+    use pairing::bls12_381::Bls12;
+    use rand::{self, Rand};
+
+    fn test_divide<E: Engine, R: rand::Rng>(rng: &mut R)
+    {
+        let worker = Worker::new();
+
+        for log_d in 3..8 {
+            let d = 1usize << log_d;
+            let num_roots = 4;
+            let q_len = d - num_roots - 1;
+
+            let roots: Vec<E::Fr> = (0..num_roots).map(|_| E::Fr::rand(rng)).collect();
+            let q: Vec<_> = (0..q_len).map(|_| Scalar::<E>(E::Fr::rand(rng))).collect();
+
+            // Naive convolution of q(X) with the vanishing polynomial of
+            // `roots`, expanding one linear factor `(X - r)` at a time:
+            // the X^(i+1) term picks up `coeff`, the X^i term picks up
+            // `-r * coeff`.
+            let mut p: Vec<E::Fr> = q.iter().map(|c| c.0).collect();
+            for r in &roots {
+                let mut next = vec![E::Fr::zero(); p.len() + 1];
+                for (i, coeff) in p.iter().enumerate() {
+                    next[i + 1].add_assign(coeff);
+                    let mut sub = *coeff;
+                    sub.mul_assign(r);
+                    next[i].sub_assign(&sub);
+                }
+                p = next;
+            }
+            let p: Vec<_> = p.into_iter().map(Scalar::<E>).collect();
+
+            let mut domain = EvaluationDomain::from_coeffs(p).unwrap();
+            domain.coset_fft(&worker);
+            domain.divide_by_vanishing_on_coset(&worker, &roots);
+            domain.icoset_fft(&worker);
+
+            let mut expected = q;
+            expected.resize(domain.coeffs.len(), Scalar(E::Fr::zero()));
+
+            assert!(expected == domain.coeffs);
+        }
+    }
+
+    let rng = &mut rand::thread_rng();
+
+    test_divide::<Bls12, _>(rng);
+}
+
+// Test `commit` against a naive sum of `coeffs[i] * powers_of_tau[i]`,
+// rather than the windowed-bucket multiexponentiation it actually uses.
+#[test]
+fn commit_consistency() {
+    use pairing::bls12_381::Bls12;
+    use pairing::CurveAffine;
+    use rand::{self, Rand};
+
+    fn naive_commit<E: Engine>(coeffs: &[Scalar<E>], powers_of_tau: &[E::G1Affine]) -> E::G1 {
+        let mut acc = E::G1::zero();
+        for (c, p) in coeffs.iter().zip(powers_of_tau.iter()) {
+            let mut term = p.into_projective();
+            term.mul_assign(c.0.into_repr());
+            acc.add_assign(&term);
+        }
+        acc
+    }
+
+    fn test_commit<E: Engine, R: rand::Rng>(rng: &mut R)
+    {
+        let worker = Worker::new();
+
+        for log_d in 0..6 {
+            let d = 1 << log_d;
+
+            let coeffs: Vec<_> = (0..d).map(|_| Scalar::<E>(E::Fr::rand(rng))).collect();
+            let domain = EvaluationDomain::from_coeffs(coeffs).unwrap();
+
+            // A toy powers-of-tau: `tau^i * G1` for an ephemeral random
+            // `tau`, just large enough to cover every (zero-padded)
+            // coefficient in `domain`.
+            let tau = E::Fr::rand(rng);
+            let mut cur = E::Fr::one();
+            let mut powers_of_tau = Vec::with_capacity(domain.coeffs.len());
+            for _ in 0..domain.coeffs.len() {
+                let mut p = E::G1::one();
+                p.mul_assign(cur.into_repr());
+                powers_of_tau.push(p.into_affine());
+                cur.mul_assign(&tau);
+            }
+
+            let expected = naive_commit::<E>(&domain.coeffs, &powers_of_tau);
+            let actual = domain.commit(&worker, &powers_of_tau);
+
+            assert!(expected == actual);
+        }
+    }
+
+    let rng = &mut rand::thread_rng();
+
+    test_commit::<Bls12, _>(rng);
+}
+
 #[test]
 fn test_field_element_multiplication_bn256() {
     use rand::{self, Rand};